@@ -0,0 +1,90 @@
+-- Generates `src/generated/<version>/buf_option.rs` from the buffer-local
+-- options known to the running Neovim.
+--
+-- Usage: nvim -l scripts/gen_buf_options.lua <output-file>
+
+local out_path = assert(arg[1], "missing output file")
+
+local infos = vim.api.nvim_get_all_options_info()
+
+local names = vim.tbl_filter(function(name)
+  return infos[name].scope == "buf"
+end, vim.tbl_keys(infos))
+table.sort(names)
+
+local rust_types = {
+  boolean = "bool",
+  number = "types::Integer",
+  string = "String",
+}
+
+local function variant(name)
+  return (name:gsub("^%l", string.upper))
+end
+
+local lines = {
+  "// @generated by `make regenerate` from Neovim "
+    .. tostring(vim.version())
+    .. ". Do not edit by hand.",
+  "",
+  "use super::OptionName;",
+  "",
+  "/// A buffer-local option together with its value, to be set with",
+  "/// [`Buffer::set_typed_option()`](crate::Buffer::set_typed_option).",
+  "///",
+  "/// Global-local options are included, in which case only the value local",
+  "/// to the buffer is set.",
+  "#[non_exhaustive]",
+  "#[derive(Clone, Debug, PartialEq, Eq, Hash)]",
+  "pub enum BufOption {",
+}
+
+for _, name in ipairs(names) do
+  local ty = assert(rust_types[infos[name].type], infos[name].type)
+  table.insert(lines, ("    /// The `'%s'` option."):format(name))
+  table.insert(lines, ("    %s(%s),"):format(variant(name), ty))
+end
+
+vim.list_extend(lines, {
+  "}",
+  "",
+  "impl BufOption {",
+  "    /// Returns the name of the option.",
+  "    #[inline]",
+  "    pub const fn name(&self) -> OptionName {",
+  "        match self {",
+})
+
+for _, name in ipairs(names) do
+  table.insert(
+    lines,
+    ("            Self::%s(_) => OptionName::%s,"):format(variant(name), variant(name))
+  )
+end
+
+vim.list_extend(lines, {
+  "        }",
+  "    }",
+  "",
+  "    /// Returns the value of the option.",
+  "    #[inline]",
+  "    pub fn into_value(self) -> types::Object {",
+  "        match self {",
+})
+
+for _, name in ipairs(names) do
+  table.insert(
+    lines,
+    ("            Self::%s(value) => value.into(),"):format(variant(name))
+  )
+end
+
+vim.list_extend(lines, {
+  "        }",
+  "    }",
+  "}",
+})
+
+local file = assert(io.open(out_path, "w"))
+file:write(table.concat(lines, "\n"), "\n")
+file:close()
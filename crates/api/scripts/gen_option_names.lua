@@ -0,0 +1,56 @@
+-- Generates `src/generated/<version>/option_name.rs` from the options known
+-- to the running Neovim.
+--
+-- Usage: nvim -l scripts/gen_option_names.lua <output-file>
+
+local out_path = assert(arg[1], "missing output file")
+
+local names = vim.tbl_keys(vim.api.nvim_get_all_options_info())
+table.sort(names)
+
+local function variant(name)
+  return (name:gsub("^%l", string.upper))
+end
+
+local lines = {
+  "// @generated by `make regenerate` from Neovim "
+    .. tostring(vim.version())
+    .. ". Do not edit by hand.",
+  "",
+  "/// The name of a Neovim option.",
+  "///",
+  "/// Use [`as_str`](OptionName::as_str) or the `Display` implementation to",
+  "/// get the full name of the option to pass to the option functions.",
+  "#[non_exhaustive]",
+  "#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]",
+  "pub enum OptionName {",
+}
+
+for _, name in ipairs(names) do
+  table.insert(lines, ("    /// The `'%s'` option."):format(name))
+  table.insert(lines, ("    %s,"):format(variant(name)))
+end
+
+vim.list_extend(lines, {
+  "}",
+  "",
+  "impl OptionName {",
+  "    /// Returns the full name of the option.",
+  "    #[inline]",
+  "    pub const fn as_str(self) -> &'static str {",
+  "        match self {",
+})
+
+for _, name in ipairs(names) do
+  table.insert(lines, ('            Self::%s => "%s",'):format(variant(name), name))
+end
+
+vim.list_extend(lines, {
+  "        }",
+  "    }",
+  "}",
+})
+
+local file = assert(io.open(out_path, "w"))
+file:write(table.concat(lines, "\n"), "\n")
+file:close()
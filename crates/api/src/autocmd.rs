@@ -1,3 +1,5 @@
+use core::marker::PhantomData;
+
 use types::{self as nvim, Array, Integer, Object, conversion::FromObject};
 
 use super::LUA_INTERNAL_CALL;
@@ -43,13 +45,15 @@ pub fn create_augroup(name: &str, opts: &CreateAugroupOpts) -> Result<u32> {
 
 /// Binding to [`nvim_create_autocmd()`][1].
 ///
-/// Creates a new autocommand.
+/// Creates a new autocommand. The autocommand is deleted when the returned
+/// [`AutocmdHandle`] is dropped, unless it's kept alive with
+/// [`AutocmdHandle::forget()`].
 ///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_create_autocmd()
 pub fn create_autocmd<'a, I>(
     events: I,
     opts: &CreateAutocmdOpts,
-) -> Result<u32>
+) -> Result<AutocmdHandle>
 where
     I: IntoIterator<Item = &'a str>,
 {
@@ -64,7 +68,10 @@ where
             &mut err,
         )
     };
-    choose!(err, Ok(id.try_into().expect("always positive")))
+    choose!(err, {
+        let id = id.try_into().expect("always positive");
+        Ok(AutocmdHandle(id, PhantomData))
+    })
 }
 
 /// Binding to [`nvim_del_augroup_by_id()`][1].
@@ -104,7 +111,11 @@ pub fn del_autocmd(id: u32) -> Result<()> {
 /// Binding to [`nvim_exec_autocmds()`][1].
 ///
 /// Executes all the autocommands registered on the given `events` that also
-/// match `opts`.
+/// match `opts`. This can be used to broadcast custom `User` events (e.g.
+/// `User MyPluginReady`) by passing `["User"]` as the events and the name of
+/// the event as the pattern.
+///
+/// Returns an error if any of the `events` is not a valid event name.
 ///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_exec_autocmds()
 pub fn exec_autocmds<'a, I>(events: I, opts: &ExecAutocmdsOpts) -> Result<()>
@@ -140,3 +151,45 @@ pub fn get_autocmds(
         })
     )
 }
+
+/// A handle to an autocommand created via [`create_autocmd()`], which deletes
+/// the autocommand when dropped.
+///
+/// The handle can't be sent to other threads, since dropping it calls the
+/// Neovim API.
+#[must_use = "the autocommand is deleted as soon as the handle is dropped"]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AutocmdHandle(u32, PhantomData<*const ()>);
+
+impl AutocmdHandle {
+    /// Deletes the autocommand, returning an error if it couldn't be deleted,
+    /// e.g. because it was a [`once`](CreateAutocmdOptsBuilder::once)
+    /// autocommand that already ran. Dropping the handle deletes it too, but
+    /// ignores any error.
+    #[inline]
+    pub fn delete(self) -> Result<()> {
+        del_autocmd(self.forget())
+    }
+
+    /// Consumes the handle without deleting the autocommand, returning its
+    /// id. The autocommand can then only be deleted with [`del_autocmd()`].
+    #[inline]
+    pub fn forget(self) -> u32 {
+        let id = self.0;
+        core::mem::forget(self);
+        id
+    }
+
+    /// Returns the id of the autocommand.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.0
+    }
+}
+
+impl Drop for AutocmdHandle {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = del_autocmd(self.0);
+    }
+}
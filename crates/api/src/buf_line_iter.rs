@@ -0,0 +1,107 @@
+use core::ops::{Bound, RangeBounds};
+
+use types as nvim;
+
+use crate::Buffer;
+use crate::Result;
+use crate::opts::BufIterOpts;
+
+/// An iterator over the lines of a [`Buffer`], created by
+/// [`Buffer::iter_lines()`].
+///
+/// Lines are fetched from Neovim in batches of
+/// [`batch_size`](crate::opts::BufIterOptsBuilder::batch_size) lines, so at
+/// most one batch is held in memory at any given time.
+#[derive(Debug)]
+pub struct BufLineIter {
+    buffer: Buffer,
+    batch: std::vec::IntoIter<nvim::String>,
+    batch_size: usize,
+    next: usize,
+    end: usize,
+}
+
+impl Buffer {
+    /// Returns an iterator over the lines in `line_range`. Indexing is
+    /// zero-based, end-exclusive.
+    ///
+    /// Unlike [`get_lines()`](Buffer::get_lines), the lines are fetched
+    /// lazily in batches. The range is resolved and clamped to the lines in
+    /// the buffer when this is called, so if the buffer is shortened while
+    /// iterating, the iterator yields an error for the batch that's out of
+    /// bounds and then stops.
+    pub fn iter_lines<R>(
+        &self,
+        line_range: R,
+        opts: &BufIterOpts,
+    ) -> Result<BufLineIter>
+    where
+        R: RangeBounds<usize>,
+    {
+        let line_count = self.line_count()?;
+
+        let start = match line_range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match line_range.end_bound() {
+            Bound::Included(&n) => n.saturating_add(1),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => line_count,
+        }
+        .min(line_count);
+
+        Ok(BufLineIter {
+            buffer: self.clone(),
+            batch: Vec::new().into_iter(),
+            batch_size: opts.batch_size,
+            next: start.min(end),
+            end,
+        })
+    }
+}
+
+impl Iterator for BufLineIter {
+    type Item = Result<nvim::String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(line) = self.batch.next() {
+            return Some(Ok(line));
+        }
+
+        if self.next >= self.end {
+            return None;
+        }
+
+        let batch_end = self.end.min(self.next + self.batch_size);
+
+        match self.buffer.get_lines(self.next..batch_end, true) {
+            Ok(lines) => {
+                self.batch = lines.collect::<Vec<_>>().into_iter();
+                self.next = batch_end;
+                self.batch.next().map(Ok)
+            },
+            Err(err) => {
+                self.next = self.end;
+                Some(Err(err))
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for BufLineIter {
+    #[inline]
+    fn len(&self) -> usize {
+        self.batch.len() + (self.end - self.next)
+    }
+}
+
+impl core::iter::FusedIterator for BufLineIter {}
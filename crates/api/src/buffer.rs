@@ -1,7 +1,7 @@
 use core::ops::RangeBounds;
 use std::error::Error as StdError;
 use std::fmt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::result::Result as StdResult;
 
 use luajit::{self as lua, Poppable, Pushable};
@@ -21,9 +21,9 @@ use crate::SuperIterator;
 use crate::choose;
 use crate::ffi::buffer::*;
 use crate::opts::*;
-use crate::types::{KeymapInfos, Mode};
+use crate::types::{BufOption, KeymapInfos, Mode};
 use crate::utils;
-use crate::{Error, IntoResult, Result};
+use crate::{CallContext, Error, IntoResult, Result};
 
 /// A wrapper around a Neovim buffer handle.
 #[derive(Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -115,12 +115,58 @@ impl Buffer {
     ///
     /// Used to register a set of callbacks on specific buffer events.
     ///
+    /// If `send_buffer` is `true` the
+    /// [`on_lines`](BufAttachOptsBuilder::on_lines) callback is also called
+    /// right away with the whole contents of the buffer, as if every line had
+    /// just been inserted into an empty buffer, i.e. with a first and last
+    /// row of 0 and a new last row equal to the number of lines. This is
+    /// useful to process the initial contents and the following changes in
+    /// the same place. If that first call returns `true` the callbacks are
+    /// never attached. If `send_buffer` is `false` the callback is only
+    /// called on the first change.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let opts = BufAttachOpts::on_lines(|event| {
+    ///     // With `send_buffer` set to `true` this is first called with
+    ///     // `first_line: 0, last_line: 0, new_last_line: line_count`.
+    ///     reparse(&event.buffer, event.first_line..event.new_last_line);
+    /// });
+    ///
+    /// Buffer::current().attach(true, &opts)?;
+    /// ```
+    ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_buf_attach()
     pub fn attach(
         &self,
         send_buffer: bool,
         opts: &BufAttachOpts,
     ) -> Result<()> {
+        // Neovim only sends the initial contents to RPC channels, so we have
+        // to call the callback ourselves. Lua callbacks can only be detached
+        // by returning `true` from them, so this is done before attaching to
+        // honor that return value. Unloaded buffers can't be attached to, and
+        // `nvim_buf_attach()` reports that below.
+        if let Some(on_lines) =
+            opts.on_lines_ref().filter(|_| send_buffer && self.is_loaded())
+        {
+            let args: OnLinesArgs = (
+                "lines".to_owned(),
+                self.clone(),
+                self.get_changedtick()?,
+                0,
+                0,
+                self.line_count()?,
+                0,
+                None,
+                None,
+            );
+            if lua::function::call::<_, ShouldDetach>(on_lines, args)? {
+                return Ok(());
+            }
+        }
+
         let mut err = nvim::Error::new();
 
         let has_attached = unsafe {
@@ -133,18 +179,20 @@ impl Buffer {
             )
         };
 
-        choose!(
-            err,
-            match has_attached {
-                true => Ok(()),
-                _ => Err(Error::custom("Attaching to buffer failed")),
-            }
-        )
+        if err.is_err() {
+            return Err(err.into());
+        } else if !has_attached {
+            return Err(Error::custom("Attaching to buffer failed"));
+        }
+
+        Ok(())
     }
 
     /// Binding to [`nvim_buf_call()`][1].
     ///
     /// Calls a function with this buffer as the temporary current buffer.
+    /// Inside the function, [`CallContext`] can be used to get the targeted
+    /// buffer.
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_buf_call()
     pub fn call<F, Res, Ret>(&self, fun: F) -> Result<Ret>
@@ -154,7 +202,11 @@ impl Buffer {
         Res::Error: StdError + 'static,
         Ret: Pushable + FromObject,
     {
-        let fun = Function::from_fn_once(fun);
+        let target = self.clone();
+        let fun = Function::from_fn_once(move |args| {
+            let _frame = CallContext::enter_buf(target);
+            fun(args)
+        });
         let mut err = nvim::Error::new();
 
         let ref_or_nil =
@@ -188,6 +240,13 @@ impl Buffer {
         })
     }
 
+    /// Removes the name of the buffer, making it unnamed. This is the same as
+    /// calling [`set_name()`](Buffer::set_name) with an empty path.
+    #[inline]
+    pub fn clear_name(&mut self) -> Result<()> {
+        self.set_name("")
+    }
+
     /// Binding to [`nvim_buf_del_keymap()`][1].
     ///
     /// Unmaps a buffer-local mapping for the given mode.
@@ -355,13 +414,14 @@ impl Buffer {
 
     /// Binding to [`nvim_buf_get_name()`][1].
     ///
-    /// Returns the full filepath of the buffer.
+    /// Returns the full filepath of the buffer, or an empty path if the
+    /// buffer is unnamed.
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_buf_get_name()
-    pub fn get_name(&self) -> Result<nvim::String> {
+    pub fn get_name(&self) -> Result<PathBuf> {
         let mut err = nvim::Error::new();
         let name = unsafe { nvim_buf_get_name(self.0, &mut err) };
-        choose!(err, Ok(name.into()))
+        choose!(err, Ok(nvim::String::from(name).into()))
     }
 
     /// Binding to [`nvim_buf_get_offset()`][1].
@@ -576,16 +636,37 @@ impl Buffer {
 
     /// Binding to [`nvim_buf_set_name()`][1].
     ///
-    /// Sets the full file name for a buffer.
+    /// Sets the full file name for a buffer. The name doesn't have to be a
+    /// valid path, e.g. `"MyPlugin://tree"` is a common choice for scratch
+    /// buffers. Returns an error if another buffer already has that name.
+    ///
+    /// Setting the name the buffer already has is a no-op, so it doesn't
+    /// trigger the `BufFilePre` and `BufFilePost` autocommands or mark the
+    /// buffer as not edited. Relative names are resolved to a full path
+    /// first, like Neovim does, before comparing them.
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_buf_set_name()
     pub fn set_name<Name: AsRef<Path>>(&mut self, name: Name) -> Result<()> {
         let name = nvim::String::from(name.as_ref());
+        if self.get_name()? == full_name(&name)? {
+            return Ok(());
+        }
         let mut err = nvim::Error::new();
         unsafe { nvim_buf_set_name(self.0, name.as_nvim_str(), &mut err) };
         choose!(err, ())
     }
 
+    /// Sets the value of a buffer-local option in this buffer.
+    ///
+    /// Unlike [`set_option_value()`](crate::set_option_value), this can't be
+    /// used to set a global option by mistake, since [`BufOption`] only lists
+    /// the buffer-local ones. For global-local options only the local value
+    /// is set.
+    pub fn set_typed_option(&mut self, opt: BufOption) -> Result<()> {
+        let opts = OptionOpts::builder().buf(self.clone()).build();
+        crate::set_option_value(opt.name().as_str(), opt.into_value(), &opts)
+    }
+
     /// Binding to [`nvim_buf_set_text()`][1].
     ///
     /// Sets (replaces) a range in the buffer. Indexing is zero-based, with
@@ -648,3 +729,19 @@ impl Buffer {
         choose!(err, ())
     }
 }
+
+/// Returns the name Neovim gives a buffer when it's renamed to `name`, i.e.
+/// its full path, or `name` itself if it's empty or a URL like
+/// `"MyPlugin://tree"`.
+fn full_name(name: &nvim::String) -> Result<PathBuf> {
+    if name.is_empty() {
+        return Ok(PathBuf::new());
+    }
+    // `fnamemodify()` resolves the name the same way `nvim_buf_set_name()`
+    // does, relative to Neovim's current directory.
+    crate::call_function::<_, nvim::String>(
+        "fnamemodify",
+        (name.clone(), ":p"),
+    )
+    .map(Into::into)
+}
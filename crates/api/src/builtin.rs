@@ -0,0 +1,167 @@
+//! Typed wrappers around some of Vimscript's builtin functions (see `:h
+//! builtin-functions`), called via [`call_function()`].
+
+use std::path::PathBuf;
+
+use luajit::Pushable;
+use types::Array;
+
+use crate::Buffer;
+use crate::Error;
+use crate::Result;
+use crate::call_function;
+use crate::types::{CwdScope, SignDefinition, SynAttr};
+
+/// Wrapper around [`bufexists()`][1].
+///
+/// Returns `true` if a buffer matching `expr` exists. Unlike [`bufnr()`],
+/// `expr` must be the exact name of the buffer. Unlisted buffers are also
+/// found.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#bufexists()
+pub fn bufexists(expr: &str) -> bool {
+    call_function::<_, i64>("bufexists", (expr,)).is_ok_and(|res| res == 1)
+}
+
+/// Wrapper around [`bufname()`][1].
+///
+/// Returns the name of the buffer as it's displayed by `:ls`, which is
+/// relative to the current directory for files inside it. Use
+/// [`Buffer::get_name()`] to get the full path instead.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#bufname()
+pub fn bufname(buf: &Buffer) -> Result<String> {
+    call_function("bufname", (buf.handle(),))
+}
+
+/// Wrapper around [`bufnr()`][1].
+///
+/// Returns the buffer matching `expr`, or `None` if there's no such
+/// buffer. Besides buffer names and patterns, `expr` can be `"%"` for the
+/// current buffer or `"#"` for the alternate buffer.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#bufnr()
+pub fn bufnr(expr: &str) -> Result<Option<Buffer>> {
+    let bufnr = call_function::<_, i32>("bufnr", (expr,))?;
+    Ok((bufnr != -1).then(|| bufnr.into()))
+}
+
+/// Wrapper around [`char2nr()`][1].
+///
+/// Returns the Unicode codepoint of `ch`.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#char2nr()
+pub fn char2nr(ch: char) -> Result<u32> {
+    let mut buf = [0; 4];
+    call_function("char2nr", (&*ch.encode_utf8(&mut buf),))
+}
+
+/// Wrapper around [`synID()`][1].
+///
+/// Returns the ID of the syntax item at the given (1,0)-indexed position in
+/// `buf`, or 0 if there isn't one. Transparent items are resolved to the
+/// item they're contained in, so the ID is the one of the group that is
+/// actually displayed.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#synID()
+pub fn get_syntax_id_at(buf: &Buffer, row: usize, col: usize) -> Result<u32> {
+    let row = types::Integer::try_from(row)?;
+    let col = types::Integer::try_from(col)? + 1;
+    buf.call(move |()| call_function::<_, u32>("synID", (row, col, true)))
+}
+
+/// Wrapper around [`getcwd()`][1].
+///
+/// Returns the working directory of the given `scope`.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#getcwd()
+pub fn getcwd(scope: CwdScope) -> Result<PathBuf> {
+    let cwd = match scope {
+        CwdScope::Global => call_function::<_, String>("getcwd", (-1,))?,
+        CwdScope::Window(win) => {
+            let tabnr = win.get_tabpage()?.get_number()?;
+            call_function("getcwd", (win.handle(), tabnr))?
+        },
+        CwdScope::Tab(tab) => {
+            call_function("getcwd", (-1, tab.get_number()?))?
+        },
+    };
+    Ok(cwd.into())
+}
+
+/// Wrapper around [`has()`][1].
+///
+/// Returns `true` if `feature` is supported by the running Neovim, e.g. one
+/// of the constants in [`features`](crate::features). Unknown features are
+/// simply not supported.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#has()
+pub fn has(feature: &str) -> bool {
+    call_function::<_, i64>("has", (feature,)).is_ok_and(|res| res == 1)
+}
+
+/// Wrapper around [`nr2char()`][1].
+///
+/// Returns the character with the Unicode codepoint `nr`, or an
+/// [`InvalidCodepoint`](Error::InvalidCodepoint) error if `nr` isn't a valid
+/// codepoint. Since Neovim always uses UTF-8 internally, `utf8` is passed
+/// through for parity with Vim but doesn't change the result.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#nr2char()
+pub fn nr2char(nr: u32, utf8: bool) -> Result<char> {
+    if char::from_u32(nr).is_none() {
+        return Err(Error::InvalidCodepoint(nr));
+    }
+    let ch = call_function::<_, String>("nr2char", (nr, utf8))?;
+    let mut chars = ch.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Ok(ch),
+        _ => Err(Error::InvalidCodepoint(nr)),
+    }
+}
+
+/// Wrapper around [`sign_getdefined()`][1].
+///
+/// Returns the definition of the sign type called `name`, or of all the
+/// defined sign types if `name` is `None`. The result is empty if there's no
+/// sign type with that name.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#sign_getdefined()
+pub fn sign_getdefined(name: Option<&str>) -> Result<Vec<SignDefinition>> {
+    match name {
+        Some(name) => call_function("sign_getdefined", (name,)),
+        None => call_function("sign_getdefined", Array::new()),
+    }
+}
+
+/// Wrapper around [`synIDattr()`][1].
+///
+/// Returns the given attribute of the syntax group with ID `id`, e.g. one
+/// returned by [`get_syntax_id_at()`]. The result is an empty string if the
+/// attribute isn't set.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#synIDattr()
+pub fn syn_id_attr(id: u32, attr: SynAttr) -> Result<String> {
+    call_function("synIDattr", (id, attr.as_str()))
+}
+
+/// Wrapper around [`printf()`][1].
+///
+/// Formats `args` according to `fmt`, which uses the `%`-items described
+/// in `:h printf()`, e.g. `%s`, `%05d` or `%x`. Returns an error if `fmt`
+/// is invalid or doesn't match the number or types of the arguments.
+///
+/// Unlike the other functions in this module this goes through Lua's
+/// `vim.fn.printf()`, so `args` can be any [`Pushable`] value, e.g. a tuple
+/// with one element per `%`-item.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#printf()
+pub fn vimscript_printf(fmt: &str, args: impl Pushable) -> Result<String> {
+    // Going through a table drops the `nil` pushed by `()`, which would be
+    // passed to `printf()` as an extra argument otherwise.
+    const PRINTF: &str = r#"
+        local fmt = ...
+        return vim.fn.printf(fmt, unpack({ select(2, ...) }))
+    "#;
+    crate::exec_lua(PRINTF, (fmt, args))
+}
@@ -0,0 +1,81 @@
+use std::cell::RefCell;
+
+use crate::{Buffer, Window};
+
+thread_local! {
+    static FRAMES: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Tracks the functions currently running inside [`Buffer::call()`] and
+/// [`Window::call()`], which can be nested.
+///
+/// Inside those functions Neovim temporarily changes the current buffer and
+/// window, so [`get_current_buf()`](crate::get_current_buf) and
+/// [`get_current_win()`](crate::get_current_win) may return something other
+/// than the buffer or window that was targeted, e.g. the hidden window Neovim
+/// uses to run commands in a buffer that isn't displayed anywhere. The
+/// functions of this type always return the target of the innermost call.
+#[derive(Copy, Clone, Debug)]
+pub struct CallContext {
+    _priv: (),
+}
+
+struct Frame {
+    buffer: Buffer,
+    window: Option<Window>,
+}
+
+/// Pops the frame it was created for when dropped, even if the function it
+/// guards panics.
+pub(crate) struct FrameGuard {
+    _priv: (),
+}
+
+impl CallContext {
+    /// Returns the buffer targeted by the innermost [`Buffer::call()`], or
+    /// the buffer shown in the window targeted by the innermost
+    /// [`Window::call()`], whichever is the most recent. Returns `None` if
+    /// this isn't called inside either of them.
+    pub fn current_buf() -> Option<Buffer> {
+        FRAMES.with_borrow(|frames| {
+            frames.last().map(|frame| frame.buffer.clone())
+        })
+    }
+
+    /// Returns the window targeted by the innermost [`Window::call()`], or
+    /// `None` if this isn't called inside one.
+    pub fn current_win() -> Option<Window> {
+        FRAMES.with_borrow(|frames| {
+            frames.iter().rev().find_map(|frame| frame.window.clone())
+        })
+    }
+
+    /// Returns how many calls to [`Buffer::call()`] and [`Window::call()`]
+    /// are currently nested, or 0 if this isn't called inside any of them.
+    pub fn depth() -> usize {
+        FRAMES.with_borrow(Vec::len)
+    }
+
+    /// Records that a function called by [`Buffer::call()`] is running.
+    pub(crate) fn enter_buf(buffer: Buffer) -> FrameGuard {
+        Self::enter(Frame { buffer, window: None })
+    }
+
+    /// Records that a function called by [`Window::call()`] is running.
+    pub(crate) fn enter_win(window: Window) -> FrameGuard {
+        // The window is the current one at this point, so the current buffer
+        // is the one it shows.
+        Self::enter(Frame { buffer: Buffer::current(), window: Some(window) })
+    }
+
+    fn enter(frame: Frame) -> FrameGuard {
+        FRAMES.with_borrow_mut(|frames| frames.push(frame));
+        FrameGuard { _priv: () }
+    }
+}
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        FRAMES.with_borrow_mut(|frames| frames.pop());
+    }
+}
@@ -0,0 +1,304 @@
+//! Colors as they appear in the Neovim API.
+
+use core::str::FromStr;
+
+use luajit::{self as lua, Poppable, Pushable};
+use serde::{Deserialize, de};
+use types::{
+    Integer,
+    Object,
+    conversion::{self, FromObject},
+    serde::Deserializer,
+};
+
+use crate::{Error, Result};
+
+/// A color, in any of the formats accepted by Neovim.
+///
+/// Two colors are only equal if they're in the same format, e.g.
+/// `Color::Rgb(255, 0, 0)` and `Color::Hex("#ff0000".into())` are different.
+/// Compare the results of [`to_rgb()`](Color::to_rgb) to check whether they
+/// represent the same color.
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Color {
+    /// A 24-bit RGB color. This is how Neovim returns colors, e.g. in
+    /// [`HighlightInfos`](crate::types::HighlightInfos).
+    Rgb(u8, u8, u8),
+
+    /// A hexadecimal color string of the form `"#rrggbb"`.
+    Hex(String),
+
+    /// The name of a color in [`get_color_map()`](crate::get_color_map), e.g.
+    /// `"Red"` or `"LightSlateGray"`. Names are case-insensitive.
+    Named(String),
+
+    /// One of the 256 colors of the terminal's palette, as used by the
+    /// `ctermfg` and `ctermbg` attributes (see `:h cterm-colors`).
+    Terminal(u8),
+}
+
+impl Color {
+    /// Returns the red, green and blue components of the color.
+    ///
+    /// Named colors are resolved with
+    /// [`get_color_by_name()`](crate::get_color_by_name). The first 16
+    /// [`Terminal`](Color::Terminal) colors are defined by the terminal's
+    /// theme and can't be resolved, so they return an error.
+    pub fn to_rgb(&self) -> Result<(u8, u8, u8)> {
+        match self {
+            Self::Rgb(r, g, b) => Ok((*r, *g, *b)),
+            Self::Hex(hex) => parse_hex(hex).map(split_rgb).ok_or_else(|| {
+                Error::custom(format!("{hex:?} is not a valid hex color"))
+            }),
+            Self::Named(name) => crate::get_color_by_name(name).map(split_rgb),
+            Self::Terminal(idx) => terminal_to_rgb(*idx).ok_or_else(|| {
+                Error::custom(format!(
+                    "terminal color {idx} depends on the terminal's theme"
+                ))
+            }),
+        }
+    }
+}
+
+/// Splits a `0xRRGGBB` value into its components, ignoring the upper byte.
+#[inline]
+fn split_rgb(rgb: u32) -> (u8, u8, u8) {
+    ((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// Parses a `"#rrggbb"` string into a `0xRRGGBB` value.
+fn parse_hex(hex: &str) -> Option<u32> {
+    let digits = hex.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Returns the RGB value of the colors in the 6x6x6 cube and the grayscale
+/// ramp of the xterm 256-color palette, i.e. every color but the first 16.
+fn terminal_to_rgb(idx: u8) -> Option<(u8, u8, u8)> {
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match idx {
+        0..=15 => None,
+        16..=231 => {
+            let idx = (idx - 16) as usize;
+            let level = |n: usize| CUBE_LEVELS[n % 6];
+            Some((level(idx / 36), level(idx / 6), level(idx)))
+        },
+        232..=255 => {
+            let gray = 8 + 10 * (idx - 232);
+            Some((gray, gray, gray))
+        },
+    }
+}
+
+impl FromStr for Color {
+    type Err = Error;
+
+    /// Parses a `"#rrggbb"` string into a [`Hex`](Color::Hex) color, and any
+    /// other non-empty string into a [`Named`](Color::Named) color, without
+    /// checking that the name exists.
+    fn from_str(s: &str) -> Result<Self> {
+        if s.starts_with('#') {
+            parse_hex(s).map(|_| Self::Hex(s.to_owned())).ok_or_else(|| {
+                Error::custom(format!("{s:?} is not a valid hex color"))
+            })
+        } else if s.is_empty() {
+            Err(Error::custom("color names can't be empty"))
+        } else {
+            Ok(Self::Named(s.to_owned()))
+        }
+    }
+}
+
+impl From<u32> for Color {
+    /// Converts a `0xRRGGBB` value into an [`Rgb`](Color::Rgb) color.
+    #[inline]
+    fn from(rgb: u32) -> Self {
+        let (r, g, b) = split_rgb(rgb);
+        Self::Rgb(r, g, b)
+    }
+}
+
+impl TryFrom<Color> for Object {
+    type Error = Error;
+
+    /// Converts the color into a value accepted by the `fg`, `bg` and `sp`
+    /// attributes of a highlight group.
+    ///
+    /// [`Terminal`](Color::Terminal) colors return an error, since Neovim
+    /// reads integers as RGB values there. Pass their index to `ctermfg` or
+    /// `ctermbg` instead, or convert them with [`to_rgb()`](Color::to_rgb).
+    fn try_from(color: Color) -> Result<Self> {
+        match color {
+            Color::Rgb(r, g, b) => {
+                let rgb =
+                    (r as Integer) << 16 | (g as Integer) << 8 | b as Integer;
+                Ok(rgb.into())
+            },
+            Color::Hex(str) | Color::Named(str) => Ok(str.into()),
+            Color::Terminal(idx) => Err(Error::custom(format!(
+                "terminal color {idx} can only be used as a cterm color"
+            ))),
+        }
+    }
+}
+
+impl Color {
+    /// Converts the color into a value accepted by the `fg`, `bg` and `sp`
+    /// attributes, or `None` if it's a [`Terminal`](Color::Terminal) color.
+    pub(crate) fn into_gui_object(self) -> Option<Object> {
+        Object::try_from(self).ok()
+    }
+
+    /// Converts the color into a value accepted by the `ctermfg` and
+    /// `ctermbg` attributes, i.e. a palette index or a color name, or `None`
+    /// if it's an RGB color.
+    pub(crate) fn into_cterm_object(self) -> Option<Object> {
+        match self {
+            Self::Terminal(idx) => Some((idx as Integer).into()),
+            Self::Named(name) => Some(name.into()),
+            Self::Rgb(..) | Self::Hex(_) => None,
+        }
+    }
+
+    /// Reinterprets an [`Rgb`](Color::Rgb) color decoded from an integer as
+    /// the [`Terminal`](Color::Terminal) color with that index, which is what
+    /// Neovim returns for the cterm attributes. Returns `None` if the integer
+    /// is not a valid index.
+    pub(crate) fn rgb_to_terminal(self) -> Option<Self> {
+        match self {
+            Self::Rgb(0, 0, idx) => Some(Self::Terminal(idx)),
+            Self::Rgb(..) => None,
+            other => Some(other),
+        }
+    }
+}
+
+/// Deserializes an optional integer as a [`Terminal`](Color::Terminal)
+/// color, for the fields holding cterm attributes.
+pub(crate) fn deserialize_terminal<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Color>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    Option::<Color>::deserialize(deserializer)?
+        .map(|color| {
+            color.rgb_to_terminal().ok_or_else(|| {
+                de::Error::custom("terminal colors range from 0 to 255")
+            })
+        })
+        .transpose()
+}
+
+impl FromObject for Color {
+    /// Integers are converted into [`Rgb`](Color::Rgb) colors, and strings
+    /// are parsed with [`Color::from_str()`]. There's no way to tell a
+    /// [`Terminal`](Color::Terminal) color from an RGB one, so they're never
+    /// returned, but the cterm attributes of
+    /// [`HighlightInfos`](crate::types::HighlightInfos) are decoded as
+    /// terminal colors.
+    fn from_object(
+        obj: Object,
+    ) -> std::result::Result<Self, conversion::Error> {
+        Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Rgb(u32),
+            Str(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Rgb(rgb) if rgb <= 0xFFFFFF => Ok(rgb.into()),
+            Repr::Rgb(rgb) => Err(de::Error::custom(format!(
+                "{rgb:#x} is not a 24-bit RGB color"
+            ))),
+            Repr::Str(str) => str.parse().map_err(de::Error::custom),
+        }
+    }
+}
+
+impl Poppable for Color {
+    unsafe fn pop(
+        lstate: *mut lua::ffi::State,
+    ) -> std::result::Result<Self, lua::Error> {
+        let obj = unsafe { Object::pop(lstate) }?;
+
+        Self::from_object(obj)
+            .map_err(lua::Error::pop_error_from_err::<Self, _>)
+    }
+}
+
+impl Pushable for Color {
+    unsafe fn push(self, lstate: *mut lua::ffi::State) -> std::ffi::c_int {
+        match Object::try_from(self) {
+            Ok(obj) => unsafe { obj.push(lstate) },
+            Err(err) => unsafe { lua::utils::push_error(&err, lstate) },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!("#a0b1c2".parse(), Ok(Color::Hex("#a0b1c2".into())));
+        assert_eq!("Red".parse(), Ok(Color::Named("Red".into())));
+        assert!("#a0b1c".parse::<Color>().is_err());
+        assert!("#a0b1cz".parse::<Color>().is_err());
+        assert!("".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn hex_and_terminal_to_rgb() {
+        assert_eq!(parse_hex("#A0b1C2").map(split_rgb), Some((160, 177, 194)));
+        assert_eq!(parse_hex("A0b1C2"), None);
+        assert_eq!(split_rgb(0x123456), (0x12, 0x34, 0x56));
+        assert_eq!(terminal_to_rgb(16), Some((0, 0, 0)));
+        assert_eq!(terminal_to_rgb(196), Some((255, 0, 0)));
+        assert_eq!(terminal_to_rgb(231), Some((255, 255, 255)));
+        assert_eq!(terminal_to_rgb(244), Some((128, 128, 128)));
+        assert_eq!(terminal_to_rgb(1), None);
+    }
+
+    #[test]
+    fn terminal_to_object() {
+        assert_eq!(Object::try_from(Color::Rgb(1, 2, 3)), Ok(0x010203.into()));
+        assert!(Object::try_from(Color::Terminal(196)).is_err());
+    }
+
+    #[test]
+    fn gui_and_cterm_objects() {
+        assert_eq!(Color::Terminal(196).into_gui_object(), None);
+        assert_eq!(Color::Terminal(196).into_cterm_object(), Some(196.into()));
+        assert_eq!(
+            Color::Named("Red".into()).into_cterm_object(),
+            Some("Red".into())
+        );
+        assert_eq!(Color::Rgb(1, 2, 3).into_cterm_object(), None);
+    }
+
+    #[test]
+    fn rgb_to_terminal() {
+        assert_eq!(
+            Color::Rgb(0, 0, 196).rgb_to_terminal(),
+            Some(Color::Terminal(196))
+        );
+        assert_eq!(Color::Rgb(0, 1, 0).rgb_to_terminal(), None);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::opts::CmdOpts;
+use crate::types::{CmdInfos, CmdRange};
+use crate::{Buffer, Error, Result, Window};
+
+/// A builder for the Ex commands executed by [`cmd()`](crate::cmd), which
+/// checks that the parts of the command fit together before running it.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Same as `:%substitute/a\/b/c/g`.
+/// CommandBuilder::new("substitute")
+///     .range(LineRange::WholeBuffer)
+///     .pattern("a/b")
+///     .replacement("c")
+///     .flags("g")
+///     .exec(&Default::default())?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommandBuilder {
+    cmd: String,
+    args: Vec<String>,
+    bang: bool,
+    count: Option<u32>,
+    flags: Option<String>,
+    pattern: Option<String>,
+    range: Option<LineRange>,
+    reg: Option<char>,
+    replacement: Option<String>,
+}
+
+/// The lines a command built by [`CommandBuilder`] operates on.
+///
+/// Line numbers are 1-indexed, like in Ex ranges.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum LineRange {
+    /// The line the cursor is on, like `.`.
+    CurrentLine,
+
+    /// All the lines of the current buffer, like `%`.
+    WholeBuffer,
+
+    /// A single line.
+    Line(usize),
+
+    /// The lines from the first to the second one, both included.
+    Lines(usize, usize),
+}
+
+impl CommandBuilder {
+    /// Creates a builder for the command with the given name, e.g. `"edit"`
+    /// or `"substitute"`.
+    #[inline]
+    pub fn new(cmd: impl Into<String>) -> Self {
+        Self {
+            cmd: cmd.into(),
+            args: Vec::new(),
+            bang: false,
+            count: None,
+            flags: None,
+            pattern: None,
+            range: None,
+            reg: None,
+            replacement: None,
+        }
+    }
+
+    /// Appends an argument to the command.
+    #[inline]
+    pub fn arg(&mut self, arg: impl Into<String>) -> &mut Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Appends multiple arguments to the command.
+    #[inline]
+    pub fn args<S, I>(&mut self, args: I) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Whether to add a `!` after the command name.
+    #[inline]
+    pub fn bang(&mut self, bang: bool) -> &mut Self {
+        self.bang = bang;
+        self
+    }
+
+    /// The count given to the command. Can't be used together with
+    /// [`range()`](Self::range).
+    #[inline]
+    pub fn count(&mut self, count: u32) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// The flags of a `:substitute`, e.g. `"gc"`. Requires a
+    /// [`pattern()`](Self::pattern).
+    #[inline]
+    pub fn flags(&mut self, flags: impl Into<String>) -> &mut Self {
+        self.flags = Some(flags.into());
+        self
+    }
+
+    /// The pattern of a `:substitute`. Any unescaped `/` in it is escaped so
+    /// that it doesn't end the pattern, but the rest is still a Vim regex
+    /// (see `:h pattern`). To match it literally, start it with `\V` or
+    /// escape its special characters.
+    #[inline]
+    pub fn pattern(&mut self, pattern: impl Into<String>) -> &mut Self {
+        self.pattern = Some(pattern.into());
+        self
+    }
+
+    /// The lines the command operates on. Can't be used together with
+    /// [`count()`](Self::count).
+    #[inline]
+    pub fn range(&mut self, range: LineRange) -> &mut Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// The register given to the command, e.g. `'a'` for `:yank a`.
+    #[inline]
+    pub fn reg(&mut self, reg: char) -> &mut Self {
+        self.reg = Some(reg);
+        self
+    }
+
+    /// The replacement of a `:substitute`. Requires a
+    /// [`pattern()`](Self::pattern). Like in the pattern, any unescaped `/`
+    /// is escaped.
+    #[inline]
+    pub fn replacement(
+        &mut self,
+        replacement: impl Into<String>,
+    ) -> &mut Self {
+        self.replacement = Some(replacement.into());
+        self
+    }
+
+    /// Checks the parts of the command and turns them into the [`CmdInfos`]
+    /// passed to [`cmd()`](crate::cmd).
+    ///
+    /// [`LineRange::CurrentLine`] and [`LineRange::WholeBuffer`] are resolved
+    /// against the current window and buffer at this point.
+    pub fn build(&self) -> Result<CmdInfos> {
+        if self.cmd.is_empty() {
+            return Err(Error::custom("the command name can't be empty"));
+        }
+
+        if self.range.is_some() && self.count.is_some() {
+            return Err(Error::custom(
+                "a command can't have both a range and a count",
+            ));
+        }
+
+        if self.pattern.is_none()
+            && (self.replacement.is_some() || self.flags.is_some())
+        {
+            return Err(Error::custom(
+                "the replacement and the flags require a pattern",
+            ));
+        }
+
+        let mut infos = CmdInfos::builder();
+
+        infos.cmd(self.cmd.as_str()).bang(self.bang);
+
+        let substitution = self.pattern.as_deref().map(|pattern| {
+            format!(
+                "/{}/{}/{}",
+                escape_slashes(pattern),
+                escape_slashes(self.replacement.as_deref().unwrap_or("")),
+                self.flags.as_deref().unwrap_or(""),
+            )
+        });
+
+        infos.args(substitution.into_iter().chain(self.args.iter().cloned()));
+
+        if let Some(count) = self.count {
+            infos.count(count);
+        }
+
+        if let Some(range) = self.range {
+            infos.range(range.resolve()?);
+        }
+
+        if let Some(reg) = self.reg {
+            infos.reg(reg);
+        }
+
+        Ok(infos.build())
+    }
+
+    /// Builds the command and executes it with [`cmd()`](crate::cmd),
+    /// returning its output if [`CmdOpts`] asked for it.
+    #[inline]
+    pub fn exec(&self, opts: &CmdOpts) -> Result<Option<String>> {
+        crate::cmd(&self.build()?, opts)
+    }
+}
+
+impl LineRange {
+    fn resolve(self) -> Result<CmdRange> {
+        Ok(match self {
+            Self::CurrentLine => {
+                CmdRange::Single(Window::current().get_cursor()?.0)
+            },
+            Self::WholeBuffer => {
+                CmdRange::Double(1, Buffer::current().line_count()?)
+            },
+            Self::Line(line) => CmdRange::Single(line),
+            Self::Lines(start, end) => CmdRange::Double(start, end),
+        })
+    }
+}
+
+/// Puts a backslash in front of every `/` that isn't already escaped, so it
+/// can't be mistaken for the delimiter of a `:substitute`.
+fn escape_slashes(str: &str) -> String {
+    let mut escaped = String::with_capacity(str.len());
+    let mut after_backslash = false;
+    for ch in str.chars() {
+        if ch == '/' && !after_backslash {
+            escaped.push('\\');
+        }
+        after_backslash = ch == '\\' && !after_backslash;
+        escaped.push(ch);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_slashes_once() {
+        assert_eq!(escape_slashes("a/b"), r"a\/b");
+        assert_eq!(escape_slashes(r"a\/b"), r"a\/b");
+        assert_eq!(escape_slashes(r"a\\/b"), r"a\\\/b");
+        assert_eq!(escape_slashes("//"), r"\/\/");
+        assert_eq!(escape_slashes(r"\d+"), r"\d+");
+    }
+}
@@ -33,6 +33,10 @@ pub fn exec(src: &str, output: bool) -> Result<Option<String>> {
 ///
 /// Gets a highlight definition by id.
 ///
+/// If `rgb` is `false` the colors are the ones used in terminals without true
+/// colors, and they're returned as [`Terminal`](crate::color::Color::Terminal)
+/// colors.
+///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_get_hl_by_id[1]
 ///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_get_current_win()
@@ -47,13 +51,20 @@ pub fn get_hl_by_id(hl_id: u32, rgb: bool) -> Result<HighlightInfos> {
         nvim_get_hl_by_id(hl_id.into(), rgb, core::ptr::null_mut(), &mut err)
     };
 
-    choose!(err, Ok(HighlightInfos::from_object(hl.into())?))
+    choose!(err, {
+        let infos = HighlightInfos::from_object(hl.into())?;
+        if rgb { Ok(infos) } else { infos.rgb_to_terminal() }
+    })
 }
 
 /// Binding to [`nvim_get_hl_by_name()`][1].
 ///
 /// Gets a highlight definition by name.
 ///
+/// If `rgb` is `false` the colors are the ones used in terminals without true
+/// colors, and they're returned as [`Terminal`](crate::color::Color::Terminal)
+/// colors.
+///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_get_hl_by_name()
 #[cfg_attr(
     feature = "neovim-0-11", // On 0.11 and Nightly.
@@ -70,12 +81,18 @@ pub fn get_hl_by_name(name: &str, rgb: bool) -> Result<HighlightInfos> {
             &mut err,
         )
     };
-    choose!(err, Ok(HighlightInfos::from_object(hl.into())?))
+    choose!(err, {
+        let infos = HighlightInfos::from_object(hl.into())?;
+        if rgb { Ok(infos) } else { infos.rgb_to_terminal() }
+    })
 }
 
 /// Binding to [`nvim_get_option()`][1].
 ///
-/// Gets the value of a global option.
+/// Gets the value of a global option. Prefer
+/// [`get_option_value()`](crate::get_option_value) with
+/// [`OptionScope::Global`](crate::opts::OptionScope::Global), which is
+/// available on all the supported Neovim versions.
 ///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_get_option()
 #[cfg_attr(
@@ -138,7 +155,10 @@ pub fn notify(
 
 /// Binding to [`nvim_set_option()`][1].
 ///
-/// Sets the global value of an option.
+/// Sets the global value of an option. Prefer
+/// [`set_option_value()`](crate::set_option_value) with
+/// [`OptionScope::Global`](crate::opts::OptionScope::Global), which is
+/// available on all the supported Neovim versions.
 ///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_set_option()
 #[cfg_attr(
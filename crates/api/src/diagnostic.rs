@@ -0,0 +1,86 @@
+//! Functions to work with diagnostics, built on top of Neovim's
+//! [`vim.diagnostic`][1] Lua module.
+//!
+//! [1]: https://neovim.io/doc/user/diagnostic.html#diagnostic-api
+
+use crate::Result;
+use crate::opts::{DiagnosticEnableOpts, DiagnosticFloatOpts};
+use crate::{Buffer, Window};
+
+/// Binding to [`vim.diagnostic.enable()`][1] with `enable` set to `false`.
+///
+/// Hides the diagnostics of `buf`, or of all the buffers if `buf` is `None`,
+/// until they're enabled again with [`enable()`]. The diagnostics aren't
+/// removed, so they're shown again when they're enabled.
+///
+/// [1]: https://neovim.io/doc/user/diagnostic.html#vim.diagnostic.enable()
+pub fn disable(
+    buf: Option<&Buffer>,
+    opts: &DiagnosticEnableOpts,
+) -> Result<()> {
+    set_enabled(false, buf, opts)
+}
+
+/// Binding to [`vim.diagnostic.enable()`][1].
+///
+/// Shows the diagnostics of `buf`, or of all the buffers if `buf` is `None`,
+/// after they've been hidden by [`disable()`].
+///
+/// [1]: https://neovim.io/doc/user/diagnostic.html#vim.diagnostic.enable()
+pub fn enable(
+    buf: Option<&Buffer>,
+    opts: &DiagnosticEnableOpts,
+) -> Result<()> {
+    set_enabled(true, buf, opts)
+}
+
+fn set_enabled(
+    enable: bool,
+    buf: Option<&Buffer>,
+    opts: &DiagnosticEnableOpts,
+) -> Result<()> {
+    const ENABLE: &str = r#"
+        local enable, buf, filter = ...
+        filter.bufnr = buf
+        vim.diagnostic.enable(enable, filter)
+    "#;
+
+    crate::exec_lua(ENABLE, (enable, buf.cloned(), opts.to_dict()))
+}
+
+/// Binding to [`vim.diagnostic.open_float()`][1].
+///
+/// Opens a floating window showing the diagnostics of `buf`, or of the
+/// current buffer if `buf` is `None`. Returns the window and the buffer of
+/// the float, or `None` if there are no diagnostics to show.
+///
+/// [1]: https://neovim.io/doc/user/diagnostic.html#vim.diagnostic.open_float()
+pub fn open_float(
+    buf: Option<&Buffer>,
+    opts: &DiagnosticFloatOpts,
+) -> Result<Option<(Window, Buffer)>> {
+    const OPEN_FLOAT: &str = r#"
+        local buf, opts = ...
+        opts.bufnr = buf
+        local float_buf, win = vim.diagnostic.open_float(opts)
+        if float_buf == nil then return nil end
+        return win, float_buf
+    "#;
+
+    crate::exec_lua(OPEN_FLOAT, (buf.cloned(), opts.to_dict()))
+}
+
+/// Binding to [`vim.diagnostic.reset()`][1].
+///
+/// Removes all the diagnostics in the `ns_id` namespace from `buf`, or from
+/// all the buffers if `buf` is `None`.
+///
+/// [1]: https://neovim.io/doc/user/diagnostic.html#vim.diagnostic.reset()
+pub fn reset(ns_id: u32, buf: Option<&Buffer>) -> Result<()> {
+    const RESET: &str = r#"
+        local ns_id, buf = ...
+        vim.diagnostic.reset(ns_id, buf)
+    "#;
+
+    crate::exec_lua(RESET, (ns_id, buf.cloned()))
+}
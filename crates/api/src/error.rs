@@ -12,6 +12,18 @@ pub enum Error {
     #[error(transparent)]
     FromUtf8(#[from] std::string::FromUtf8Error),
 
+    #[error("invalid Unicode codepoint: {0:#x}")]
+    InvalidCodepoint(u32),
+
+    #[error(transparent)]
+    Lua(#[from] luajit::Error),
+
+    #[error("Lua module not found: {0}")]
+    LuaModuleNotFound(String),
+
+    #[error("Lua syntax error: {0}")]
+    LuaSyntaxError(String),
+
     #[error(transparent)]
     Nvim(#[from] types::Error),
 
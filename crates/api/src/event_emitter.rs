@@ -0,0 +1,93 @@
+use core::marker::PhantomData;
+
+use types::conversion::{FromObject, ToObject};
+
+use crate::opts::{CreateAutocmdOpts, ExecAutocmdsOpts};
+use crate::types::AutocmdCallbackArgs;
+use crate::{AutocmdHandle, Error, Result};
+
+/// A typed `User` autocommand event (see `:h User`), which can be used to
+/// notify other plugins, or other parts of the same plugin, that something
+/// happened.
+///
+/// The payload of type `T` is passed to the subscribers as the autocommand's
+/// [`data`](AutocmdCallbackArgs::data), so it can also be read from Lua
+/// autocommands listening for the same event.
+///
+/// # Examples
+///
+/// ```ignore
+/// let done = EventEmitter::<String>::new("MyPluginDone");
+///
+/// let _subscription = done.subscribe(|file| {
+///     nvim_oxi::print!("finished processing {file}");
+/// })?;
+///
+/// done.emit("foo.txt".to_owned())?;
+/// ```
+pub struct EventEmitter<T> {
+    pattern: String,
+    _data: PhantomData<fn(T) -> T>,
+}
+
+impl<T> EventEmitter<T> {
+    /// Creates an emitter for the `User` event with the given pattern, e.g.
+    /// `"MyPluginDone"`.
+    #[inline]
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self { pattern: pattern.into(), _data: PhantomData }
+    }
+
+    /// Returns the pattern of the `User` event.
+    #[inline]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl<T: ToObject> EventEmitter<T> {
+    /// Triggers the event, calling all the subscribers with `data`.
+    pub fn emit(&self, data: T) -> Result<()> {
+        let opts = ExecAutocmdsOpts::builder()
+            .patterns(self.pattern.as_str())
+            .data(data.to_object()?)
+            .build();
+        crate::exec_autocmds(["User"], &opts)
+    }
+}
+
+impl<T: FromObject + 'static> EventEmitter<T> {
+    /// Registers `callback` to be called every time the event is emitted.
+    ///
+    /// The subscription lasts until the returned handle is dropped. If the
+    /// payload can't be converted to a `T`, e.g. because the event was
+    /// triggered from Lua with some other data, the callback isn't called and
+    /// the conversion error is reported by Neovim.
+    pub fn subscribe<F>(&self, callback: F) -> Result<AutocmdHandle>
+    where
+        F: Fn(T) + 'static,
+    {
+        let opts = CreateAutocmdOpts::builder()
+            .patterns([self.pattern.as_str()])
+            .callback(move |args: AutocmdCallbackArgs| {
+                callback(T::from_object(args.data)?);
+                Ok::<_, Error>(false)
+            })
+            .build();
+        crate::create_autocmd(["User"], &opts)
+    }
+}
+
+impl<T> Clone for EventEmitter<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.pattern.clone())
+    }
+}
+
+impl<T> core::fmt::Debug for EventEmitter<T> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EventEmitter").field("pattern", &self.pattern).finish()
+    }
+}
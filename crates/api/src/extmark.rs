@@ -1,6 +1,7 @@
+use core::marker::PhantomData;
 use std::ops::RangeBounds;
 
-use types::{self as nvim, Array, Integer, conversion::FromObject};
+use types::{self as nvim, Integer, conversion::FromObject};
 
 use crate::Buffer;
 use crate::SuperIterator;
@@ -24,6 +25,54 @@ pub fn create_namespace(name: &str) -> u32 {
         .expect("always positive")
 }
 
+/// A namespace that clears its highlights, extmarks and virtual text from
+/// all the buffers when it's dropped.
+///
+/// Neovim has no way to delete a namespace, so the id stays reserved and
+/// calling [`create_namespace()`] with the same name returns it again. Use
+/// [`forget()`](Self::forget) to keep the namespace's objects around.
+///
+/// The handle can't be sent to other threads, since dropping it calls the
+/// Neovim API.
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Namespace(u32, PhantomData<*const ()>);
+
+impl Namespace {
+    /// Creates a new namespace or gets an existing one via
+    /// [`create_namespace()`].
+    ///
+    /// Dropping the returned handle clears the namespace even if it existed
+    /// before, so two handles shouldn't be created with the same name.
+    #[inline]
+    pub fn create(name: &str) -> Self {
+        Self(create_namespace(name), PhantomData)
+    }
+
+    /// Returns the id of the namespace.
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.0
+    }
+
+    /// Consumes the handle without clearing the namespace, returning its id.
+    #[inline]
+    pub fn forget(self) -> u32 {
+        let id = self.0;
+        core::mem::forget(self);
+        id
+    }
+}
+
+impl Drop for Namespace {
+    fn drop(&mut self) {
+        for mut buf in crate::list_bufs() {
+            // There's nothing to do with the error if a buffer can't be
+            // cleared, and the other buffers should still be.
+            let _ = buf.clear_namespace(self.0, ..);
+        }
+    }
+}
+
 /// Binding to [`nvim_get_namespaces()`][1].
 ///
 /// Returns an iterator over all the existing, non-anonymous namespace names
@@ -42,6 +91,9 @@ pub fn get_namespaces() -> impl SuperIterator<(String, u32)> + use<> {
 ///
 /// Sets or changes a decoration provider for a namespace.
 ///
+/// Neovim doesn't pass the namespace to the callbacks in `opts`, so the ones
+/// that need it should capture `ns_id`.
+///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_set_decoration_provider()
 pub fn set_decoration_provider(
     ns_id: u32,
@@ -86,6 +138,38 @@ impl Buffer {
         choose!(err, Ok(ns_id))
     }
 
+    /// Highlights the buffer incrementally, only updating the lines that are
+    /// about to be drawn instead of the whole buffer on every change.
+    ///
+    /// Before each redraw of a window showing this buffer, `on_line` is
+    /// called with the buffer, the window's handle and the 0-indexed start
+    /// and end rows of the range being drawn. It's meant to add ephemeral
+    /// extmarks (see [`SetExtmarkOptsBuilder::ephemeral`]) in the `ns_id`
+    /// namespace, which only last for the current redraw.
+    ///
+    /// This sets the decoration provider of `ns_id` (see
+    /// [`set_decoration_provider()`]), replacing any provider previously set
+    /// for that namespace, so the namespace shouldn't be shared with other
+    /// buffers.
+    pub fn attach_highlights<F>(&self, ns_id: u32, on_line: F) -> Result<()>
+    where
+        F: Fn(&Buffer, u32, usize, usize) + 'static,
+    {
+        let target = self.clone();
+        let opts = DecorationProviderOpts::builder()
+            .on_win(move |(_, win, buf, toprow, botrow): OnWinArgs| {
+                if buf == target {
+                    let win = win.handle() as u32;
+                    on_line(&buf, win, toprow as usize, botrow as usize);
+                }
+                // The whole range has already been highlighted, so there's no
+                // need for the per-line callback.
+                false
+            })
+            .build();
+        set_decoration_provider(ns_id, &opts)
+    }
+
     /// Binding to [`nvim_buf_clear_namespace()`][1].
     ///
     /// Clears namespaced objects like highlights, extmarks, or virtual text
@@ -144,9 +228,10 @@ impl Buffer {
 
     /// Binding to [`nvim_buf_get_extmark_by_id()`][1].
     ///
-    /// The first two elements of the returned tuple represent the 0-indexed
-    /// `row, col` position of the extmark. The last element is only present if
-    /// the [`details`](crate::opts::GetExtmarkByIdOptsBuilder::details) option
+    /// Returns the extmark with the given id, or `None` if there's no such
+    /// extmark in the `ns_id` namespace. Its
+    /// [`details`](Extmark::details) are only present if the
+    /// [`details`](crate::opts::GetExtmarkByIdOptsBuilder::details) option
     /// field was set to `true`.
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_buf_get_extmark_by_id()
@@ -155,7 +240,7 @@ impl Buffer {
         ns_id: u32,
         extmark_id: u32,
         opts: &GetExtmarkByIdOpts,
-    ) -> Result<(usize, usize, Option<ExtmarkInfos>)> {
+    ) -> Result<Option<Extmark>> {
         let mut err = nvim::Error::new();
         let tuple = unsafe {
             nvim_buf_get_extmark_by_id(
@@ -168,10 +253,9 @@ impl Buffer {
             )
         };
         choose!(err, {
+            // Neovim returns an empty array if the extmark doesn't exist.
             if tuple.is_empty() {
-                return Err(Error::custom(format!(
-                    "No extmark with id {extmark_id} was found"
-                )));
+                return Ok(None);
             }
 
             let mut iter = tuple.into_iter();
@@ -179,18 +263,18 @@ impl Buffer {
                 usize::from_object(iter.next().expect("row is present"))?;
             let col =
                 usize::from_object(iter.next().expect("col is present"))?;
-            let infos =
+            let details =
                 iter.next().map(ExtmarkInfos::from_object).transpose()?;
-            Ok((row, col, infos))
+            Ok(Some(Extmark { id: extmark_id, row, col, details }))
         })
     }
 
     /// Bindings to [`nvim_buf_get_extmarks`][1].
     ///
     /// Gets all the extmarks in a buffer region specified by start and end
-    /// positions. Returns an iterator over `(extmark_id, row, col, infos)`
-    /// tuples in "traversal order". Like for [`Buffer::get_extmark_by_id`],
-    /// the `infos` are present only if the
+    /// positions. Returns an iterator over the [`Extmark`]s in "traversal
+    /// order". Like for [`Buffer::get_extmark_by_id`], their
+    /// [`details`](Extmark::details) are present only if the
     /// [`details`](crate::opts::GetExtmarksOptsBuilder::details) option field
     /// was set to `true`.
     ///
@@ -201,9 +285,7 @@ impl Buffer {
         start: ExtmarkPosition,
         end: ExtmarkPosition,
         opts: &GetExtmarksOpts,
-    ) -> Result<
-        impl SuperIterator<(u32, usize, usize, Option<ExtmarkInfos>)> + use<NsId>,
-    > {
+    ) -> Result<impl SuperIterator<Extmark> + use<NsId>> {
         let mut err = nvim::Error::new();
         let extmarks = unsafe {
             nvim_buf_get_extmarks(
@@ -219,26 +301,9 @@ impl Buffer {
         choose!(
             err,
             Ok({
-                extmarks.into_iter().map(|tuple| {
-                    let mut iter =
-                        Array::from_object(tuple).unwrap().into_iter();
-                    let id =
-                        u32::from_object(iter.next().expect("id is present"))
-                            .unwrap();
-                    let row = usize::from_object(
-                        iter.next().expect("row is present"),
-                    )
-                    .unwrap();
-                    let col = usize::from_object(
-                        iter.next().expect("col is present"),
-                    )
-                    .unwrap();
-                    let infos = iter
-                        .next()
-                        .map(ExtmarkInfos::from_object)
-                        .transpose()
-                        .unwrap();
-                    (id, row, col, infos)
+                extmarks.into_iter().map(|extmark| {
+                    Extmark::from_object(extmark)
+                        .expect("extmark is an [id, row, col] array")
                 })
             })
         )
@@ -270,4 +335,34 @@ impl Buffer {
         };
         choose!(err, Ok(id.try_into().expect("always positive")))
     }
+
+    /// Shows `chunks` as virtual text on the 0-indexed `line`, creating an
+    /// extmark in the `ns_id` namespace. Every `(text, highlights)` tuple is
+    /// a chunk of text highlighted with the given groups, like in
+    /// [`SetExtmarkOptsBuilder::virt_text`].
+    ///
+    /// Returns the id of the extmark, which can be passed to
+    /// [`del_extmark()`](Buffer::del_extmark) to remove the virtual text.
+    pub fn set_virtual_text<Text, Hl, Chunks>(
+        &mut self,
+        ns_id: u32,
+        line: usize,
+        chunks: Chunks,
+        opts: &VirtTextOpts,
+    ) -> Result<u32>
+    where
+        Chunks: IntoIterator<Item = (Text, Hl)>,
+        Text: Into<nvim::String>,
+        Hl: crate::SetExtmarkHlGroup,
+    {
+        let mut builder = SetExtmarkOpts::builder();
+        builder.virt_text(chunks);
+        if let Some(hl_mode) = opts.hl_mode {
+            builder.hl_mode(hl_mode);
+        }
+        if let Some(virt_text_pos) = opts.virt_text_pos {
+            builder.virt_text_pos(virt_text_pos);
+        }
+        self.set_extmark(ns_id, line, 0, &builder.build())
+    }
 }
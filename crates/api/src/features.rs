@@ -0,0 +1,28 @@
+//! Names of some of the features that can be checked with [`has()`]. See
+//! `:h feature-list` for the full list.
+//!
+//! [`has()`]: crate::has
+
+/// Always true in Neovim.
+pub const NVIM: &str = "nvim";
+
+/// True on Neovim 0.10 or later.
+pub const NVIM_0_10: &str = "nvim-0.10";
+
+/// True on Neovim 0.11 or later.
+pub const NVIM_0_11: &str = "nvim-0.11";
+
+/// True on Linux.
+pub const LINUX: &str = "linux";
+
+/// True on macOS.
+pub const MAC: &str = "mac";
+
+/// True on Unix-like systems, including macOS.
+pub const UNIX: &str = "unix";
+
+/// True on Windows.
+pub const WIN32: &str = "win32";
+
+/// True when running under the Windows Subsystem for Linux.
+pub const WSL: &str = "wsl";
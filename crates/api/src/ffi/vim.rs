@@ -2,8 +2,7 @@ use types::*;
 
 use crate::opts::*;
 
-// On 0.10 and 0.11.
-#[cfg(all(feature = "neovim-0-10", not(feature = "neovim-nightly")))]
+#[cfg(not(feature = "neovim-nightly"))] // On 0.10 and 0.11.
 type NvimEchoOutput = ();
 
 // Only on Nightly.
@@ -71,6 +70,12 @@ unsafe extern "C" {
     // https://github.com/neovim/neovim/blob/v0.10.0/src/nvim/api/vim.c#L274
     pub(crate) fn nvim_feedkeys(keys: NvimStr, mode: NvimStr, escape_ks: bool);
 
+    // https://github.com/neovim/neovim/blob/v0.10.0/src/nvim/api/vim.c
+    pub(crate) fn nvim_get_api_info(
+        channel_id: u64,
+        arena: *mut Arena,
+    ) -> Array;
+
     // https://github.com/neovim/neovim/blob/v0.10.0/src/nvim/api/vim.c#L1679
     pub(crate) fn nvim_get_chan_info(
         chan: Integer,
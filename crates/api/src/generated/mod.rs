@@ -0,0 +1,64 @@
+//! Code generated from Neovim's own metadata.
+//!
+//! The files in this module are checked in so that building the crate doesn't
+//! require a Neovim binary. There's one set of files for every supported
+//! Neovim version, and the one matching the enabled features is used. Run
+//! `make regenerate` from the repository root to update them after bumping
+//! the supported Neovim versions.
+
+#[cfg(feature = "neovim-nightly")] // Only on Nightly.
+mod nightly;
+#[cfg(not(feature = "neovim-0-11"))] // Only on 0.10.
+mod v0_10;
+// Only on 0.11.
+#[cfg(all(feature = "neovim-0-11", not(feature = "neovim-nightly")))]
+mod v0_11;
+
+use core::fmt;
+
+#[cfg(feature = "neovim-nightly")] // Only on Nightly.
+pub use nightly::{BufOption, OptionName};
+#[cfg(not(feature = "neovim-0-11"))] // Only on 0.10.
+pub use v0_10::{BufOption, OptionName};
+// Only on 0.11.
+#[cfg(all(feature = "neovim-0-11", not(feature = "neovim-nightly")))]
+pub use v0_11::{BufOption, OptionName};
+
+impl fmt::Display for OptionName {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for OptionName {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<OptionName> for types::String {
+    #[inline]
+    fn from(name: OptionName) -> Self {
+        name.as_str().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_name_display() {
+        assert_eq!(OptionName::Tabstop.to_string(), "tabstop");
+        assert_eq!(OptionName::Winfixbuf.as_str(), "winfixbuf");
+    }
+
+    #[test]
+    fn buf_option_name() {
+        let opt = BufOption::Expandtab(true);
+        assert_eq!(opt.name(), OptionName::Expandtab);
+        assert_eq!(opt.into_value(), types::Object::from(true));
+    }
+}
@@ -0,0 +1,5 @@
+mod buf_option;
+mod option_name;
+
+pub use buf_option::BufOption;
+pub use option_name::OptionName;
@@ -0,0 +1,369 @@
+// @generated by `make regenerate` from Neovim 0.11.0. Do not edit by hand.
+
+use super::OptionName;
+
+/// A buffer-local option together with its value, to be set with
+/// [`Buffer::set_typed_option()`](crate::Buffer::set_typed_option).
+///
+/// Global-local options are included, in which case only the value local
+/// to the buffer is set.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BufOption {
+    /// The `'autoindent'` option.
+    Autoindent(bool),
+    /// The `'autoread'` option.
+    Autoread(bool),
+    /// The `'backupcopy'` option.
+    Backupcopy(String),
+    /// The `'binary'` option.
+    Binary(bool),
+    /// The `'bomb'` option.
+    Bomb(bool),
+    /// The `'bufhidden'` option.
+    Bufhidden(String),
+    /// The `'buflisted'` option.
+    Buflisted(bool),
+    /// The `'buftype'` option.
+    Buftype(String),
+    /// The `'channel'` option.
+    Channel(types::Integer),
+    /// The `'cindent'` option.
+    Cindent(bool),
+    /// The `'cinkeys'` option.
+    Cinkeys(String),
+    /// The `'cinoptions'` option.
+    Cinoptions(String),
+    /// The `'cinscopedecls'` option.
+    Cinscopedecls(String),
+    /// The `'cinwords'` option.
+    Cinwords(String),
+    /// The `'comments'` option.
+    Comments(String),
+    /// The `'commentstring'` option.
+    Commentstring(String),
+    /// The `'complete'` option.
+    Complete(String),
+    /// The `'completefunc'` option.
+    Completefunc(String),
+    /// The `'copyindent'` option.
+    Copyindent(bool),
+    /// The `'define'` option.
+    Define(String),
+    /// The `'dictionary'` option.
+    Dictionary(String),
+    /// The `'endoffile'` option.
+    Endoffile(bool),
+    /// The `'endofline'` option.
+    Endofline(bool),
+    /// The `'equalprg'` option.
+    Equalprg(String),
+    /// The `'errorformat'` option.
+    Errorformat(String),
+    /// The `'expandtab'` option.
+    Expandtab(bool),
+    /// The `'fileencoding'` option.
+    Fileencoding(String),
+    /// The `'fileformat'` option.
+    Fileformat(String),
+    /// The `'filetype'` option.
+    Filetype(String),
+    /// The `'findfunc'` option.
+    Findfunc(String),
+    /// The `'fixendofline'` option.
+    Fixendofline(bool),
+    /// The `'formatexpr'` option.
+    Formatexpr(String),
+    /// The `'formatlistpat'` option.
+    Formatlistpat(String),
+    /// The `'formatoptions'` option.
+    Formatoptions(String),
+    /// The `'formatprg'` option.
+    Formatprg(String),
+    /// The `'grepprg'` option.
+    Grepprg(String),
+    /// The `'iminsert'` option.
+    Iminsert(types::Integer),
+    /// The `'imsearch'` option.
+    Imsearch(types::Integer),
+    /// The `'include'` option.
+    Include(String),
+    /// The `'includeexpr'` option.
+    Includeexpr(String),
+    /// The `'indentexpr'` option.
+    Indentexpr(String),
+    /// The `'indentkeys'` option.
+    Indentkeys(String),
+    /// The `'infercase'` option.
+    Infercase(bool),
+    /// The `'iskeyword'` option.
+    Iskeyword(String),
+    /// The `'keymap'` option.
+    Keymap(String),
+    /// The `'keywordprg'` option.
+    Keywordprg(String),
+    /// The `'lisp'` option.
+    Lisp(bool),
+    /// The `'lispoptions'` option.
+    Lispoptions(String),
+    /// The `'lispwords'` option.
+    Lispwords(String),
+    /// The `'makeencoding'` option.
+    Makeencoding(String),
+    /// The `'makeprg'` option.
+    Makeprg(String),
+    /// The `'matchpairs'` option.
+    Matchpairs(String),
+    /// The `'modeline'` option.
+    Modeline(bool),
+    /// The `'modifiable'` option.
+    Modifiable(bool),
+    /// The `'modified'` option.
+    Modified(bool),
+    /// The `'nrformats'` option.
+    Nrformats(String),
+    /// The `'omnifunc'` option.
+    Omnifunc(String),
+    /// The `'path'` option.
+    Path(String),
+    /// The `'preserveindent'` option.
+    Preserveindent(bool),
+    /// The `'quoteescape'` option.
+    Quoteescape(String),
+    /// The `'readonly'` option.
+    Readonly(bool),
+    /// The `'scrollback'` option.
+    Scrollback(types::Integer),
+    /// The `'shiftwidth'` option.
+    Shiftwidth(types::Integer),
+    /// The `'smartindent'` option.
+    Smartindent(bool),
+    /// The `'softtabstop'` option.
+    Softtabstop(types::Integer),
+    /// The `'spellcapcheck'` option.
+    Spellcapcheck(String),
+    /// The `'spellfile'` option.
+    Spellfile(String),
+    /// The `'spelllang'` option.
+    Spelllang(String),
+    /// The `'spelloptions'` option.
+    Spelloptions(String),
+    /// The `'suffixesadd'` option.
+    Suffixesadd(String),
+    /// The `'swapfile'` option.
+    Swapfile(bool),
+    /// The `'synmaxcol'` option.
+    Synmaxcol(types::Integer),
+    /// The `'syntax'` option.
+    Syntax(String),
+    /// The `'tabstop'` option.
+    Tabstop(types::Integer),
+    /// The `'tagcase'` option.
+    Tagcase(String),
+    /// The `'tagfunc'` option.
+    Tagfunc(String),
+    /// The `'tags'` option.
+    Tags(String),
+    /// The `'textwidth'` option.
+    Textwidth(types::Integer),
+    /// The `'thesaurus'` option.
+    Thesaurus(String),
+    /// The `'thesaurusfunc'` option.
+    Thesaurusfunc(String),
+    /// The `'undofile'` option.
+    Undofile(bool),
+    /// The `'undolevels'` option.
+    Undolevels(types::Integer),
+    /// The `'varsofttabstop'` option.
+    Varsofttabstop(String),
+    /// The `'vartabstop'` option.
+    Vartabstop(String),
+    /// The `'wrapmargin'` option.
+    Wrapmargin(types::Integer),
+}
+
+impl BufOption {
+    /// Returns the name of the option.
+    #[inline]
+    pub const fn name(&self) -> OptionName {
+        match self {
+            Self::Autoindent(_) => OptionName::Autoindent,
+            Self::Autoread(_) => OptionName::Autoread,
+            Self::Backupcopy(_) => OptionName::Backupcopy,
+            Self::Binary(_) => OptionName::Binary,
+            Self::Bomb(_) => OptionName::Bomb,
+            Self::Bufhidden(_) => OptionName::Bufhidden,
+            Self::Buflisted(_) => OptionName::Buflisted,
+            Self::Buftype(_) => OptionName::Buftype,
+            Self::Channel(_) => OptionName::Channel,
+            Self::Cindent(_) => OptionName::Cindent,
+            Self::Cinkeys(_) => OptionName::Cinkeys,
+            Self::Cinoptions(_) => OptionName::Cinoptions,
+            Self::Cinscopedecls(_) => OptionName::Cinscopedecls,
+            Self::Cinwords(_) => OptionName::Cinwords,
+            Self::Comments(_) => OptionName::Comments,
+            Self::Commentstring(_) => OptionName::Commentstring,
+            Self::Complete(_) => OptionName::Complete,
+            Self::Completefunc(_) => OptionName::Completefunc,
+            Self::Copyindent(_) => OptionName::Copyindent,
+            Self::Define(_) => OptionName::Define,
+            Self::Dictionary(_) => OptionName::Dictionary,
+            Self::Endoffile(_) => OptionName::Endoffile,
+            Self::Endofline(_) => OptionName::Endofline,
+            Self::Equalprg(_) => OptionName::Equalprg,
+            Self::Errorformat(_) => OptionName::Errorformat,
+            Self::Expandtab(_) => OptionName::Expandtab,
+            Self::Fileencoding(_) => OptionName::Fileencoding,
+            Self::Fileformat(_) => OptionName::Fileformat,
+            Self::Filetype(_) => OptionName::Filetype,
+            Self::Findfunc(_) => OptionName::Findfunc,
+            Self::Fixendofline(_) => OptionName::Fixendofline,
+            Self::Formatexpr(_) => OptionName::Formatexpr,
+            Self::Formatlistpat(_) => OptionName::Formatlistpat,
+            Self::Formatoptions(_) => OptionName::Formatoptions,
+            Self::Formatprg(_) => OptionName::Formatprg,
+            Self::Grepprg(_) => OptionName::Grepprg,
+            Self::Iminsert(_) => OptionName::Iminsert,
+            Self::Imsearch(_) => OptionName::Imsearch,
+            Self::Include(_) => OptionName::Include,
+            Self::Includeexpr(_) => OptionName::Includeexpr,
+            Self::Indentexpr(_) => OptionName::Indentexpr,
+            Self::Indentkeys(_) => OptionName::Indentkeys,
+            Self::Infercase(_) => OptionName::Infercase,
+            Self::Iskeyword(_) => OptionName::Iskeyword,
+            Self::Keymap(_) => OptionName::Keymap,
+            Self::Keywordprg(_) => OptionName::Keywordprg,
+            Self::Lisp(_) => OptionName::Lisp,
+            Self::Lispoptions(_) => OptionName::Lispoptions,
+            Self::Lispwords(_) => OptionName::Lispwords,
+            Self::Makeencoding(_) => OptionName::Makeencoding,
+            Self::Makeprg(_) => OptionName::Makeprg,
+            Self::Matchpairs(_) => OptionName::Matchpairs,
+            Self::Modeline(_) => OptionName::Modeline,
+            Self::Modifiable(_) => OptionName::Modifiable,
+            Self::Modified(_) => OptionName::Modified,
+            Self::Nrformats(_) => OptionName::Nrformats,
+            Self::Omnifunc(_) => OptionName::Omnifunc,
+            Self::Path(_) => OptionName::Path,
+            Self::Preserveindent(_) => OptionName::Preserveindent,
+            Self::Quoteescape(_) => OptionName::Quoteescape,
+            Self::Readonly(_) => OptionName::Readonly,
+            Self::Scrollback(_) => OptionName::Scrollback,
+            Self::Shiftwidth(_) => OptionName::Shiftwidth,
+            Self::Smartindent(_) => OptionName::Smartindent,
+            Self::Softtabstop(_) => OptionName::Softtabstop,
+            Self::Spellcapcheck(_) => OptionName::Spellcapcheck,
+            Self::Spellfile(_) => OptionName::Spellfile,
+            Self::Spelllang(_) => OptionName::Spelllang,
+            Self::Spelloptions(_) => OptionName::Spelloptions,
+            Self::Suffixesadd(_) => OptionName::Suffixesadd,
+            Self::Swapfile(_) => OptionName::Swapfile,
+            Self::Synmaxcol(_) => OptionName::Synmaxcol,
+            Self::Syntax(_) => OptionName::Syntax,
+            Self::Tabstop(_) => OptionName::Tabstop,
+            Self::Tagcase(_) => OptionName::Tagcase,
+            Self::Tagfunc(_) => OptionName::Tagfunc,
+            Self::Tags(_) => OptionName::Tags,
+            Self::Textwidth(_) => OptionName::Textwidth,
+            Self::Thesaurus(_) => OptionName::Thesaurus,
+            Self::Thesaurusfunc(_) => OptionName::Thesaurusfunc,
+            Self::Undofile(_) => OptionName::Undofile,
+            Self::Undolevels(_) => OptionName::Undolevels,
+            Self::Varsofttabstop(_) => OptionName::Varsofttabstop,
+            Self::Vartabstop(_) => OptionName::Vartabstop,
+            Self::Wrapmargin(_) => OptionName::Wrapmargin,
+        }
+    }
+
+    /// Returns the value of the option.
+    #[inline]
+    pub fn into_value(self) -> types::Object {
+        match self {
+            Self::Autoindent(value) => value.into(),
+            Self::Autoread(value) => value.into(),
+            Self::Backupcopy(value) => value.into(),
+            Self::Binary(value) => value.into(),
+            Self::Bomb(value) => value.into(),
+            Self::Bufhidden(value) => value.into(),
+            Self::Buflisted(value) => value.into(),
+            Self::Buftype(value) => value.into(),
+            Self::Channel(value) => value.into(),
+            Self::Cindent(value) => value.into(),
+            Self::Cinkeys(value) => value.into(),
+            Self::Cinoptions(value) => value.into(),
+            Self::Cinscopedecls(value) => value.into(),
+            Self::Cinwords(value) => value.into(),
+            Self::Comments(value) => value.into(),
+            Self::Commentstring(value) => value.into(),
+            Self::Complete(value) => value.into(),
+            Self::Completefunc(value) => value.into(),
+            Self::Copyindent(value) => value.into(),
+            Self::Define(value) => value.into(),
+            Self::Dictionary(value) => value.into(),
+            Self::Endoffile(value) => value.into(),
+            Self::Endofline(value) => value.into(),
+            Self::Equalprg(value) => value.into(),
+            Self::Errorformat(value) => value.into(),
+            Self::Expandtab(value) => value.into(),
+            Self::Fileencoding(value) => value.into(),
+            Self::Fileformat(value) => value.into(),
+            Self::Filetype(value) => value.into(),
+            Self::Findfunc(value) => value.into(),
+            Self::Fixendofline(value) => value.into(),
+            Self::Formatexpr(value) => value.into(),
+            Self::Formatlistpat(value) => value.into(),
+            Self::Formatoptions(value) => value.into(),
+            Self::Formatprg(value) => value.into(),
+            Self::Grepprg(value) => value.into(),
+            Self::Iminsert(value) => value.into(),
+            Self::Imsearch(value) => value.into(),
+            Self::Include(value) => value.into(),
+            Self::Includeexpr(value) => value.into(),
+            Self::Indentexpr(value) => value.into(),
+            Self::Indentkeys(value) => value.into(),
+            Self::Infercase(value) => value.into(),
+            Self::Iskeyword(value) => value.into(),
+            Self::Keymap(value) => value.into(),
+            Self::Keywordprg(value) => value.into(),
+            Self::Lisp(value) => value.into(),
+            Self::Lispoptions(value) => value.into(),
+            Self::Lispwords(value) => value.into(),
+            Self::Makeencoding(value) => value.into(),
+            Self::Makeprg(value) => value.into(),
+            Self::Matchpairs(value) => value.into(),
+            Self::Modeline(value) => value.into(),
+            Self::Modifiable(value) => value.into(),
+            Self::Modified(value) => value.into(),
+            Self::Nrformats(value) => value.into(),
+            Self::Omnifunc(value) => value.into(),
+            Self::Path(value) => value.into(),
+            Self::Preserveindent(value) => value.into(),
+            Self::Quoteescape(value) => value.into(),
+            Self::Readonly(value) => value.into(),
+            Self::Scrollback(value) => value.into(),
+            Self::Shiftwidth(value) => value.into(),
+            Self::Smartindent(value) => value.into(),
+            Self::Softtabstop(value) => value.into(),
+            Self::Spellcapcheck(value) => value.into(),
+            Self::Spellfile(value) => value.into(),
+            Self::Spelllang(value) => value.into(),
+            Self::Spelloptions(value) => value.into(),
+            Self::Suffixesadd(value) => value.into(),
+            Self::Swapfile(value) => value.into(),
+            Self::Synmaxcol(value) => value.into(),
+            Self::Syntax(value) => value.into(),
+            Self::Tabstop(value) => value.into(),
+            Self::Tagcase(value) => value.into(),
+            Self::Tagfunc(value) => value.into(),
+            Self::Tags(value) => value.into(),
+            Self::Textwidth(value) => value.into(),
+            Self::Thesaurus(value) => value.into(),
+            Self::Thesaurusfunc(value) => value.into(),
+            Self::Undofile(value) => value.into(),
+            Self::Undolevels(value) => value.into(),
+            Self::Varsofttabstop(value) => value.into(),
+            Self::Vartabstop(value) => value.into(),
+            Self::Wrapmargin(value) => value.into(),
+        }
+    }
+}
@@ -0,0 +1,1042 @@
+// @generated by `make regenerate` from Neovim 0.11.0. Do not edit by hand.
+
+/// The name of a Neovim option.
+///
+/// Use [`as_str`](OptionName::as_str) or the `Display` implementation to
+/// get the full name of the option to pass to the option functions.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OptionName {
+    /// The `'aleph'` option.
+    Aleph,
+    /// The `'allowrevins'` option.
+    Allowrevins,
+    /// The `'ambiwidth'` option.
+    Ambiwidth,
+    /// The `'arabic'` option.
+    Arabic,
+    /// The `'arabicshape'` option.
+    Arabicshape,
+    /// The `'autochdir'` option.
+    Autochdir,
+    /// The `'autoindent'` option.
+    Autoindent,
+    /// The `'autoread'` option.
+    Autoread,
+    /// The `'autowrite'` option.
+    Autowrite,
+    /// The `'autowriteall'` option.
+    Autowriteall,
+    /// The `'background'` option.
+    Background,
+    /// The `'backspace'` option.
+    Backspace,
+    /// The `'backup'` option.
+    Backup,
+    /// The `'backupcopy'` option.
+    Backupcopy,
+    /// The `'backupdir'` option.
+    Backupdir,
+    /// The `'backupext'` option.
+    Backupext,
+    /// The `'backupskip'` option.
+    Backupskip,
+    /// The `'belloff'` option.
+    Belloff,
+    /// The `'binary'` option.
+    Binary,
+    /// The `'bomb'` option.
+    Bomb,
+    /// The `'breakat'` option.
+    Breakat,
+    /// The `'breakindent'` option.
+    Breakindent,
+    /// The `'breakindentopt'` option.
+    Breakindentopt,
+    /// The `'bufhidden'` option.
+    Bufhidden,
+    /// The `'buflisted'` option.
+    Buflisted,
+    /// The `'buftype'` option.
+    Buftype,
+    /// The `'casemap'` option.
+    Casemap,
+    /// The `'cdhome'` option.
+    Cdhome,
+    /// The `'cdpath'` option.
+    Cdpath,
+    /// The `'cedit'` option.
+    Cedit,
+    /// The `'channel'` option.
+    Channel,
+    /// The `'charconvert'` option.
+    Charconvert,
+    /// The `'cindent'` option.
+    Cindent,
+    /// The `'cinkeys'` option.
+    Cinkeys,
+    /// The `'cinoptions'` option.
+    Cinoptions,
+    /// The `'cinscopedecls'` option.
+    Cinscopedecls,
+    /// The `'cinwords'` option.
+    Cinwords,
+    /// The `'clipboard'` option.
+    Clipboard,
+    /// The `'cmdheight'` option.
+    Cmdheight,
+    /// The `'cmdwinheight'` option.
+    Cmdwinheight,
+    /// The `'colorcolumn'` option.
+    Colorcolumn,
+    /// The `'columns'` option.
+    Columns,
+    /// The `'comments'` option.
+    Comments,
+    /// The `'commentstring'` option.
+    Commentstring,
+    /// The `'complete'` option.
+    Complete,
+    /// The `'completefunc'` option.
+    Completefunc,
+    /// The `'completeitemalign'` option.
+    Completeitemalign,
+    /// The `'completeopt'` option.
+    Completeopt,
+    /// The `'concealcursor'` option.
+    Concealcursor,
+    /// The `'conceallevel'` option.
+    Conceallevel,
+    /// The `'confirm'` option.
+    Confirm,
+    /// The `'copyindent'` option.
+    Copyindent,
+    /// The `'cpoptions'` option.
+    Cpoptions,
+    /// The `'cursorbind'` option.
+    Cursorbind,
+    /// The `'cursorcolumn'` option.
+    Cursorcolumn,
+    /// The `'cursorline'` option.
+    Cursorline,
+    /// The `'cursorlineopt'` option.
+    Cursorlineopt,
+    /// The `'debug'` option.
+    Debug,
+    /// The `'define'` option.
+    Define,
+    /// The `'dictionary'` option.
+    Dictionary,
+    /// The `'diff'` option.
+    Diff,
+    /// The `'diffexpr'` option.
+    Diffexpr,
+    /// The `'diffopt'` option.
+    Diffopt,
+    /// The `'digraph'` option.
+    Digraph,
+    /// The `'directory'` option.
+    Directory,
+    /// The `'display'` option.
+    Display,
+    /// The `'eadirection'` option.
+    Eadirection,
+    /// The `'emoji'` option.
+    Emoji,
+    /// The `'encoding'` option.
+    Encoding,
+    /// The `'endoffile'` option.
+    Endoffile,
+    /// The `'endofline'` option.
+    Endofline,
+    /// The `'equalalways'` option.
+    Equalalways,
+    /// The `'equalprg'` option.
+    Equalprg,
+    /// The `'errorbells'` option.
+    Errorbells,
+    /// The `'errorfile'` option.
+    Errorfile,
+    /// The `'errorformat'` option.
+    Errorformat,
+    /// The `'eventignore'` option.
+    Eventignore,
+    /// The `'eventignorewin'` option.
+    Eventignorewin,
+    /// The `'expandtab'` option.
+    Expandtab,
+    /// The `'exrc'` option.
+    Exrc,
+    /// The `'fileencoding'` option.
+    Fileencoding,
+    /// The `'fileencodings'` option.
+    Fileencodings,
+    /// The `'fileformat'` option.
+    Fileformat,
+    /// The `'fileformats'` option.
+    Fileformats,
+    /// The `'fileignorecase'` option.
+    Fileignorecase,
+    /// The `'filetype'` option.
+    Filetype,
+    /// The `'fillchars'` option.
+    Fillchars,
+    /// The `'findfunc'` option.
+    Findfunc,
+    /// The `'fixendofline'` option.
+    Fixendofline,
+    /// The `'foldclose'` option.
+    Foldclose,
+    /// The `'foldcolumn'` option.
+    Foldcolumn,
+    /// The `'foldenable'` option.
+    Foldenable,
+    /// The `'foldexpr'` option.
+    Foldexpr,
+    /// The `'foldignore'` option.
+    Foldignore,
+    /// The `'foldlevel'` option.
+    Foldlevel,
+    /// The `'foldlevelstart'` option.
+    Foldlevelstart,
+    /// The `'foldmarker'` option.
+    Foldmarker,
+    /// The `'foldmethod'` option.
+    Foldmethod,
+    /// The `'foldminlines'` option.
+    Foldminlines,
+    /// The `'foldnestmax'` option.
+    Foldnestmax,
+    /// The `'foldopen'` option.
+    Foldopen,
+    /// The `'foldtext'` option.
+    Foldtext,
+    /// The `'formatexpr'` option.
+    Formatexpr,
+    /// The `'formatlistpat'` option.
+    Formatlistpat,
+    /// The `'formatoptions'` option.
+    Formatoptions,
+    /// The `'formatprg'` option.
+    Formatprg,
+    /// The `'fsync'` option.
+    Fsync,
+    /// The `'gdefault'` option.
+    Gdefault,
+    /// The `'grepformat'` option.
+    Grepformat,
+    /// The `'grepprg'` option.
+    Grepprg,
+    /// The `'guicursor'` option.
+    Guicursor,
+    /// The `'guifont'` option.
+    Guifont,
+    /// The `'guifontwide'` option.
+    Guifontwide,
+    /// The `'helpfile'` option.
+    Helpfile,
+    /// The `'helpheight'` option.
+    Helpheight,
+    /// The `'helplang'` option.
+    Helplang,
+    /// The `'hidden'` option.
+    Hidden,
+    /// The `'history'` option.
+    History,
+    /// The `'hlsearch'` option.
+    Hlsearch,
+    /// The `'icon'` option.
+    Icon,
+    /// The `'iconstring'` option.
+    Iconstring,
+    /// The `'ignorecase'` option.
+    Ignorecase,
+    /// The `'imcmdline'` option.
+    Imcmdline,
+    /// The `'iminsert'` option.
+    Iminsert,
+    /// The `'imsearch'` option.
+    Imsearch,
+    /// The `'inccommand'` option.
+    Inccommand,
+    /// The `'include'` option.
+    Include,
+    /// The `'includeexpr'` option.
+    Includeexpr,
+    /// The `'incsearch'` option.
+    Incsearch,
+    /// The `'indentexpr'` option.
+    Indentexpr,
+    /// The `'indentkeys'` option.
+    Indentkeys,
+    /// The `'infercase'` option.
+    Infercase,
+    /// The `'isfname'` option.
+    Isfname,
+    /// The `'isident'` option.
+    Isident,
+    /// The `'iskeyword'` option.
+    Iskeyword,
+    /// The `'isprint'` option.
+    Isprint,
+    /// The `'joinspaces'` option.
+    Joinspaces,
+    /// The `'jumpoptions'` option.
+    Jumpoptions,
+    /// The `'keymap'` option.
+    Keymap,
+    /// The `'keymodel'` option.
+    Keymodel,
+    /// The `'keywordprg'` option.
+    Keywordprg,
+    /// The `'langmap'` option.
+    Langmap,
+    /// The `'langmenu'` option.
+    Langmenu,
+    /// The `'langnoremap'` option.
+    Langnoremap,
+    /// The `'langremap'` option.
+    Langremap,
+    /// The `'laststatus'` option.
+    Laststatus,
+    /// The `'lazyredraw'` option.
+    Lazyredraw,
+    /// The `'linebreak'` option.
+    Linebreak,
+    /// The `'lines'` option.
+    Lines,
+    /// The `'linespace'` option.
+    Linespace,
+    /// The `'lisp'` option.
+    Lisp,
+    /// The `'lispoptions'` option.
+    Lispoptions,
+    /// The `'lispwords'` option.
+    Lispwords,
+    /// The `'list'` option.
+    List,
+    /// The `'listchars'` option.
+    Listchars,
+    /// The `'loadplugins'` option.
+    Loadplugins,
+    /// The `'magic'` option.
+    Magic,
+    /// The `'makeef'` option.
+    Makeef,
+    /// The `'makeencoding'` option.
+    Makeencoding,
+    /// The `'makeprg'` option.
+    Makeprg,
+    /// The `'matchpairs'` option.
+    Matchpairs,
+    /// The `'matchtime'` option.
+    Matchtime,
+    /// The `'maxfuncdepth'` option.
+    Maxfuncdepth,
+    /// The `'maxmapdepth'` option.
+    Maxmapdepth,
+    /// The `'maxmempattern'` option.
+    Maxmempattern,
+    /// The `'menuitems'` option.
+    Menuitems,
+    /// The `'messagesopt'` option.
+    Messagesopt,
+    /// The `'mkspellmem'` option.
+    Mkspellmem,
+    /// The `'modeline'` option.
+    Modeline,
+    /// The `'modelineexpr'` option.
+    Modelineexpr,
+    /// The `'modelines'` option.
+    Modelines,
+    /// The `'modifiable'` option.
+    Modifiable,
+    /// The `'modified'` option.
+    Modified,
+    /// The `'more'` option.
+    More,
+    /// The `'mouse'` option.
+    Mouse,
+    /// The `'mousefocus'` option.
+    Mousefocus,
+    /// The `'mousehide'` option.
+    Mousehide,
+    /// The `'mousemodel'` option.
+    Mousemodel,
+    /// The `'mousemoveevent'` option.
+    Mousemoveevent,
+    /// The `'mousescroll'` option.
+    Mousescroll,
+    /// The `'mousetime'` option.
+    Mousetime,
+    /// The `'nrformats'` option.
+    Nrformats,
+    /// The `'number'` option.
+    Number,
+    /// The `'numberwidth'` option.
+    Numberwidth,
+    /// The `'omnifunc'` option.
+    Omnifunc,
+    /// The `'operatorfunc'` option.
+    Operatorfunc,
+    /// The `'packpath'` option.
+    Packpath,
+    /// The `'paragraphs'` option.
+    Paragraphs,
+    /// The `'paste'` option.
+    Paste,
+    /// The `'patchexpr'` option.
+    Patchexpr,
+    /// The `'patchmode'` option.
+    Patchmode,
+    /// The `'path'` option.
+    Path,
+    /// The `'preserveindent'` option.
+    Preserveindent,
+    /// The `'previewheight'` option.
+    Previewheight,
+    /// The `'previewwindow'` option.
+    Previewwindow,
+    /// The `'pumblend'` option.
+    Pumblend,
+    /// The `'pumheight'` option.
+    Pumheight,
+    /// The `'pummaxwidth'` option.
+    Pummaxwidth,
+    /// The `'pumwidth'` option.
+    Pumwidth,
+    /// The `'pyxversion'` option.
+    Pyxversion,
+    /// The `'quickfixtextfunc'` option.
+    Quickfixtextfunc,
+    /// The `'quoteescape'` option.
+    Quoteescape,
+    /// The `'readonly'` option.
+    Readonly,
+    /// The `'redrawdebug'` option.
+    Redrawdebug,
+    /// The `'redrawtime'` option.
+    Redrawtime,
+    /// The `'regexpengine'` option.
+    Regexpengine,
+    /// The `'relativenumber'` option.
+    Relativenumber,
+    /// The `'report'` option.
+    Report,
+    /// The `'revins'` option.
+    Revins,
+    /// The `'rightleft'` option.
+    Rightleft,
+    /// The `'rightleftcmd'` option.
+    Rightleftcmd,
+    /// The `'ruler'` option.
+    Ruler,
+    /// The `'rulerformat'` option.
+    Rulerformat,
+    /// The `'runtimepath'` option.
+    Runtimepath,
+    /// The `'scroll'` option.
+    Scroll,
+    /// The `'scrollback'` option.
+    Scrollback,
+    /// The `'scrollbind'` option.
+    Scrollbind,
+    /// The `'scrolljump'` option.
+    Scrolljump,
+    /// The `'scrolloff'` option.
+    Scrolloff,
+    /// The `'scrollopt'` option.
+    Scrollopt,
+    /// The `'sections'` option.
+    Sections,
+    /// The `'secure'` option.
+    Secure,
+    /// The `'selection'` option.
+    Selection,
+    /// The `'selectmode'` option.
+    Selectmode,
+    /// The `'sessionoptions'` option.
+    Sessionoptions,
+    /// The `'shada'` option.
+    Shada,
+    /// The `'shadafile'` option.
+    Shadafile,
+    /// The `'shell'` option.
+    Shell,
+    /// The `'shellcmdflag'` option.
+    Shellcmdflag,
+    /// The `'shellpipe'` option.
+    Shellpipe,
+    /// The `'shellquote'` option.
+    Shellquote,
+    /// The `'shellredir'` option.
+    Shellredir,
+    /// The `'shelltemp'` option.
+    Shelltemp,
+    /// The `'shellxescape'` option.
+    Shellxescape,
+    /// The `'shellxquote'` option.
+    Shellxquote,
+    /// The `'shiftround'` option.
+    Shiftround,
+    /// The `'shiftwidth'` option.
+    Shiftwidth,
+    /// The `'shortmess'` option.
+    Shortmess,
+    /// The `'showbreak'` option.
+    Showbreak,
+    /// The `'showcmd'` option.
+    Showcmd,
+    /// The `'showcmdloc'` option.
+    Showcmdloc,
+    /// The `'showfulltag'` option.
+    Showfulltag,
+    /// The `'showmatch'` option.
+    Showmatch,
+    /// The `'showmode'` option.
+    Showmode,
+    /// The `'showtabline'` option.
+    Showtabline,
+    /// The `'sidescroll'` option.
+    Sidescroll,
+    /// The `'sidescrolloff'` option.
+    Sidescrolloff,
+    /// The `'signcolumn'` option.
+    Signcolumn,
+    /// The `'smartcase'` option.
+    Smartcase,
+    /// The `'smartindent'` option.
+    Smartindent,
+    /// The `'smarttab'` option.
+    Smarttab,
+    /// The `'smoothscroll'` option.
+    Smoothscroll,
+    /// The `'softtabstop'` option.
+    Softtabstop,
+    /// The `'spell'` option.
+    Spell,
+    /// The `'spellcapcheck'` option.
+    Spellcapcheck,
+    /// The `'spellfile'` option.
+    Spellfile,
+    /// The `'spelllang'` option.
+    Spelllang,
+    /// The `'spelloptions'` option.
+    Spelloptions,
+    /// The `'spellsuggest'` option.
+    Spellsuggest,
+    /// The `'splitbelow'` option.
+    Splitbelow,
+    /// The `'splitkeep'` option.
+    Splitkeep,
+    /// The `'splitright'` option.
+    Splitright,
+    /// The `'startofline'` option.
+    Startofline,
+    /// The `'statuscolumn'` option.
+    Statuscolumn,
+    /// The `'statusline'` option.
+    Statusline,
+    /// The `'suffixes'` option.
+    Suffixes,
+    /// The `'suffixesadd'` option.
+    Suffixesadd,
+    /// The `'swapfile'` option.
+    Swapfile,
+    /// The `'switchbuf'` option.
+    Switchbuf,
+    /// The `'synmaxcol'` option.
+    Synmaxcol,
+    /// The `'syntax'` option.
+    Syntax,
+    /// The `'tabclose'` option.
+    Tabclose,
+    /// The `'tabline'` option.
+    Tabline,
+    /// The `'tabpagemax'` option.
+    Tabpagemax,
+    /// The `'tabstop'` option.
+    Tabstop,
+    /// The `'tagbsearch'` option.
+    Tagbsearch,
+    /// The `'tagcase'` option.
+    Tagcase,
+    /// The `'tagfunc'` option.
+    Tagfunc,
+    /// The `'taglength'` option.
+    Taglength,
+    /// The `'tagrelative'` option.
+    Tagrelative,
+    /// The `'tags'` option.
+    Tags,
+    /// The `'tagstack'` option.
+    Tagstack,
+    /// The `'termbidi'` option.
+    Termbidi,
+    /// The `'termguicolors'` option.
+    Termguicolors,
+    /// The `'termpastefilter'` option.
+    Termpastefilter,
+    /// The `'termsync'` option.
+    Termsync,
+    /// The `'textwidth'` option.
+    Textwidth,
+    /// The `'thesaurus'` option.
+    Thesaurus,
+    /// The `'thesaurusfunc'` option.
+    Thesaurusfunc,
+    /// The `'tildeop'` option.
+    Tildeop,
+    /// The `'timeout'` option.
+    Timeout,
+    /// The `'timeoutlen'` option.
+    Timeoutlen,
+    /// The `'title'` option.
+    Title,
+    /// The `'titlelen'` option.
+    Titlelen,
+    /// The `'titleold'` option.
+    Titleold,
+    /// The `'titlestring'` option.
+    Titlestring,
+    /// The `'ttimeout'` option.
+    Ttimeout,
+    /// The `'ttimeoutlen'` option.
+    Ttimeoutlen,
+    /// The `'undodir'` option.
+    Undodir,
+    /// The `'undofile'` option.
+    Undofile,
+    /// The `'undolevels'` option.
+    Undolevels,
+    /// The `'undoreload'` option.
+    Undoreload,
+    /// The `'updatecount'` option.
+    Updatecount,
+    /// The `'updatetime'` option.
+    Updatetime,
+    /// The `'varsofttabstop'` option.
+    Varsofttabstop,
+    /// The `'vartabstop'` option.
+    Vartabstop,
+    /// The `'verbose'` option.
+    Verbose,
+    /// The `'verbosefile'` option.
+    Verbosefile,
+    /// The `'viewdir'` option.
+    Viewdir,
+    /// The `'viewoptions'` option.
+    Viewoptions,
+    /// The `'virtualedit'` option.
+    Virtualedit,
+    /// The `'visualbell'` option.
+    Visualbell,
+    /// The `'warn'` option.
+    Warn,
+    /// The `'whichwrap'` option.
+    Whichwrap,
+    /// The `'wildchar'` option.
+    Wildchar,
+    /// The `'wildcharm'` option.
+    Wildcharm,
+    /// The `'wildignore'` option.
+    Wildignore,
+    /// The `'wildignorecase'` option.
+    Wildignorecase,
+    /// The `'wildmenu'` option.
+    Wildmenu,
+    /// The `'wildmode'` option.
+    Wildmode,
+    /// The `'wildoptions'` option.
+    Wildoptions,
+    /// The `'winaltkeys'` option.
+    Winaltkeys,
+    /// The `'winbar'` option.
+    Winbar,
+    /// The `'winblend'` option.
+    Winblend,
+    /// The `'winborder'` option.
+    Winborder,
+    /// The `'window'` option.
+    Window,
+    /// The `'winfixbuf'` option.
+    Winfixbuf,
+    /// The `'winfixheight'` option.
+    Winfixheight,
+    /// The `'winfixwidth'` option.
+    Winfixwidth,
+    /// The `'winheight'` option.
+    Winheight,
+    /// The `'winhighlight'` option.
+    Winhighlight,
+    /// The `'winminheight'` option.
+    Winminheight,
+    /// The `'winminwidth'` option.
+    Winminwidth,
+    /// The `'winwidth'` option.
+    Winwidth,
+    /// The `'wrap'` option.
+    Wrap,
+    /// The `'wrapmargin'` option.
+    Wrapmargin,
+    /// The `'wrapscan'` option.
+    Wrapscan,
+    /// The `'write'` option.
+    Write,
+    /// The `'writeany'` option.
+    Writeany,
+    /// The `'writebackup'` option.
+    Writebackup,
+    /// The `'writedelay'` option.
+    Writedelay,
+}
+
+impl OptionName {
+    /// Returns the full name of the option.
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Aleph => "aleph",
+            Self::Allowrevins => "allowrevins",
+            Self::Ambiwidth => "ambiwidth",
+            Self::Arabic => "arabic",
+            Self::Arabicshape => "arabicshape",
+            Self::Autochdir => "autochdir",
+            Self::Autoindent => "autoindent",
+            Self::Autoread => "autoread",
+            Self::Autowrite => "autowrite",
+            Self::Autowriteall => "autowriteall",
+            Self::Background => "background",
+            Self::Backspace => "backspace",
+            Self::Backup => "backup",
+            Self::Backupcopy => "backupcopy",
+            Self::Backupdir => "backupdir",
+            Self::Backupext => "backupext",
+            Self::Backupskip => "backupskip",
+            Self::Belloff => "belloff",
+            Self::Binary => "binary",
+            Self::Bomb => "bomb",
+            Self::Breakat => "breakat",
+            Self::Breakindent => "breakindent",
+            Self::Breakindentopt => "breakindentopt",
+            Self::Bufhidden => "bufhidden",
+            Self::Buflisted => "buflisted",
+            Self::Buftype => "buftype",
+            Self::Casemap => "casemap",
+            Self::Cdhome => "cdhome",
+            Self::Cdpath => "cdpath",
+            Self::Cedit => "cedit",
+            Self::Channel => "channel",
+            Self::Charconvert => "charconvert",
+            Self::Cindent => "cindent",
+            Self::Cinkeys => "cinkeys",
+            Self::Cinoptions => "cinoptions",
+            Self::Cinscopedecls => "cinscopedecls",
+            Self::Cinwords => "cinwords",
+            Self::Clipboard => "clipboard",
+            Self::Cmdheight => "cmdheight",
+            Self::Cmdwinheight => "cmdwinheight",
+            Self::Colorcolumn => "colorcolumn",
+            Self::Columns => "columns",
+            Self::Comments => "comments",
+            Self::Commentstring => "commentstring",
+            Self::Complete => "complete",
+            Self::Completefunc => "completefunc",
+            Self::Completeitemalign => "completeitemalign",
+            Self::Completeopt => "completeopt",
+            Self::Concealcursor => "concealcursor",
+            Self::Conceallevel => "conceallevel",
+            Self::Confirm => "confirm",
+            Self::Copyindent => "copyindent",
+            Self::Cpoptions => "cpoptions",
+            Self::Cursorbind => "cursorbind",
+            Self::Cursorcolumn => "cursorcolumn",
+            Self::Cursorline => "cursorline",
+            Self::Cursorlineopt => "cursorlineopt",
+            Self::Debug => "debug",
+            Self::Define => "define",
+            Self::Dictionary => "dictionary",
+            Self::Diff => "diff",
+            Self::Diffexpr => "diffexpr",
+            Self::Diffopt => "diffopt",
+            Self::Digraph => "digraph",
+            Self::Directory => "directory",
+            Self::Display => "display",
+            Self::Eadirection => "eadirection",
+            Self::Emoji => "emoji",
+            Self::Encoding => "encoding",
+            Self::Endoffile => "endoffile",
+            Self::Endofline => "endofline",
+            Self::Equalalways => "equalalways",
+            Self::Equalprg => "equalprg",
+            Self::Errorbells => "errorbells",
+            Self::Errorfile => "errorfile",
+            Self::Errorformat => "errorformat",
+            Self::Eventignore => "eventignore",
+            Self::Eventignorewin => "eventignorewin",
+            Self::Expandtab => "expandtab",
+            Self::Exrc => "exrc",
+            Self::Fileencoding => "fileencoding",
+            Self::Fileencodings => "fileencodings",
+            Self::Fileformat => "fileformat",
+            Self::Fileformats => "fileformats",
+            Self::Fileignorecase => "fileignorecase",
+            Self::Filetype => "filetype",
+            Self::Fillchars => "fillchars",
+            Self::Findfunc => "findfunc",
+            Self::Fixendofline => "fixendofline",
+            Self::Foldclose => "foldclose",
+            Self::Foldcolumn => "foldcolumn",
+            Self::Foldenable => "foldenable",
+            Self::Foldexpr => "foldexpr",
+            Self::Foldignore => "foldignore",
+            Self::Foldlevel => "foldlevel",
+            Self::Foldlevelstart => "foldlevelstart",
+            Self::Foldmarker => "foldmarker",
+            Self::Foldmethod => "foldmethod",
+            Self::Foldminlines => "foldminlines",
+            Self::Foldnestmax => "foldnestmax",
+            Self::Foldopen => "foldopen",
+            Self::Foldtext => "foldtext",
+            Self::Formatexpr => "formatexpr",
+            Self::Formatlistpat => "formatlistpat",
+            Self::Formatoptions => "formatoptions",
+            Self::Formatprg => "formatprg",
+            Self::Fsync => "fsync",
+            Self::Gdefault => "gdefault",
+            Self::Grepformat => "grepformat",
+            Self::Grepprg => "grepprg",
+            Self::Guicursor => "guicursor",
+            Self::Guifont => "guifont",
+            Self::Guifontwide => "guifontwide",
+            Self::Helpfile => "helpfile",
+            Self::Helpheight => "helpheight",
+            Self::Helplang => "helplang",
+            Self::Hidden => "hidden",
+            Self::History => "history",
+            Self::Hlsearch => "hlsearch",
+            Self::Icon => "icon",
+            Self::Iconstring => "iconstring",
+            Self::Ignorecase => "ignorecase",
+            Self::Imcmdline => "imcmdline",
+            Self::Iminsert => "iminsert",
+            Self::Imsearch => "imsearch",
+            Self::Inccommand => "inccommand",
+            Self::Include => "include",
+            Self::Includeexpr => "includeexpr",
+            Self::Incsearch => "incsearch",
+            Self::Indentexpr => "indentexpr",
+            Self::Indentkeys => "indentkeys",
+            Self::Infercase => "infercase",
+            Self::Isfname => "isfname",
+            Self::Isident => "isident",
+            Self::Iskeyword => "iskeyword",
+            Self::Isprint => "isprint",
+            Self::Joinspaces => "joinspaces",
+            Self::Jumpoptions => "jumpoptions",
+            Self::Keymap => "keymap",
+            Self::Keymodel => "keymodel",
+            Self::Keywordprg => "keywordprg",
+            Self::Langmap => "langmap",
+            Self::Langmenu => "langmenu",
+            Self::Langnoremap => "langnoremap",
+            Self::Langremap => "langremap",
+            Self::Laststatus => "laststatus",
+            Self::Lazyredraw => "lazyredraw",
+            Self::Linebreak => "linebreak",
+            Self::Lines => "lines",
+            Self::Linespace => "linespace",
+            Self::Lisp => "lisp",
+            Self::Lispoptions => "lispoptions",
+            Self::Lispwords => "lispwords",
+            Self::List => "list",
+            Self::Listchars => "listchars",
+            Self::Loadplugins => "loadplugins",
+            Self::Magic => "magic",
+            Self::Makeef => "makeef",
+            Self::Makeencoding => "makeencoding",
+            Self::Makeprg => "makeprg",
+            Self::Matchpairs => "matchpairs",
+            Self::Matchtime => "matchtime",
+            Self::Maxfuncdepth => "maxfuncdepth",
+            Self::Maxmapdepth => "maxmapdepth",
+            Self::Maxmempattern => "maxmempattern",
+            Self::Menuitems => "menuitems",
+            Self::Messagesopt => "messagesopt",
+            Self::Mkspellmem => "mkspellmem",
+            Self::Modeline => "modeline",
+            Self::Modelineexpr => "modelineexpr",
+            Self::Modelines => "modelines",
+            Self::Modifiable => "modifiable",
+            Self::Modified => "modified",
+            Self::More => "more",
+            Self::Mouse => "mouse",
+            Self::Mousefocus => "mousefocus",
+            Self::Mousehide => "mousehide",
+            Self::Mousemodel => "mousemodel",
+            Self::Mousemoveevent => "mousemoveevent",
+            Self::Mousescroll => "mousescroll",
+            Self::Mousetime => "mousetime",
+            Self::Nrformats => "nrformats",
+            Self::Number => "number",
+            Self::Numberwidth => "numberwidth",
+            Self::Omnifunc => "omnifunc",
+            Self::Operatorfunc => "operatorfunc",
+            Self::Packpath => "packpath",
+            Self::Paragraphs => "paragraphs",
+            Self::Paste => "paste",
+            Self::Patchexpr => "patchexpr",
+            Self::Patchmode => "patchmode",
+            Self::Path => "path",
+            Self::Preserveindent => "preserveindent",
+            Self::Previewheight => "previewheight",
+            Self::Previewwindow => "previewwindow",
+            Self::Pumblend => "pumblend",
+            Self::Pumheight => "pumheight",
+            Self::Pummaxwidth => "pummaxwidth",
+            Self::Pumwidth => "pumwidth",
+            Self::Pyxversion => "pyxversion",
+            Self::Quickfixtextfunc => "quickfixtextfunc",
+            Self::Quoteescape => "quoteescape",
+            Self::Readonly => "readonly",
+            Self::Redrawdebug => "redrawdebug",
+            Self::Redrawtime => "redrawtime",
+            Self::Regexpengine => "regexpengine",
+            Self::Relativenumber => "relativenumber",
+            Self::Report => "report",
+            Self::Revins => "revins",
+            Self::Rightleft => "rightleft",
+            Self::Rightleftcmd => "rightleftcmd",
+            Self::Ruler => "ruler",
+            Self::Rulerformat => "rulerformat",
+            Self::Runtimepath => "runtimepath",
+            Self::Scroll => "scroll",
+            Self::Scrollback => "scrollback",
+            Self::Scrollbind => "scrollbind",
+            Self::Scrolljump => "scrolljump",
+            Self::Scrolloff => "scrolloff",
+            Self::Scrollopt => "scrollopt",
+            Self::Sections => "sections",
+            Self::Secure => "secure",
+            Self::Selection => "selection",
+            Self::Selectmode => "selectmode",
+            Self::Sessionoptions => "sessionoptions",
+            Self::Shada => "shada",
+            Self::Shadafile => "shadafile",
+            Self::Shell => "shell",
+            Self::Shellcmdflag => "shellcmdflag",
+            Self::Shellpipe => "shellpipe",
+            Self::Shellquote => "shellquote",
+            Self::Shellredir => "shellredir",
+            Self::Shelltemp => "shelltemp",
+            Self::Shellxescape => "shellxescape",
+            Self::Shellxquote => "shellxquote",
+            Self::Shiftround => "shiftround",
+            Self::Shiftwidth => "shiftwidth",
+            Self::Shortmess => "shortmess",
+            Self::Showbreak => "showbreak",
+            Self::Showcmd => "showcmd",
+            Self::Showcmdloc => "showcmdloc",
+            Self::Showfulltag => "showfulltag",
+            Self::Showmatch => "showmatch",
+            Self::Showmode => "showmode",
+            Self::Showtabline => "showtabline",
+            Self::Sidescroll => "sidescroll",
+            Self::Sidescrolloff => "sidescrolloff",
+            Self::Signcolumn => "signcolumn",
+            Self::Smartcase => "smartcase",
+            Self::Smartindent => "smartindent",
+            Self::Smarttab => "smarttab",
+            Self::Smoothscroll => "smoothscroll",
+            Self::Softtabstop => "softtabstop",
+            Self::Spell => "spell",
+            Self::Spellcapcheck => "spellcapcheck",
+            Self::Spellfile => "spellfile",
+            Self::Spelllang => "spelllang",
+            Self::Spelloptions => "spelloptions",
+            Self::Spellsuggest => "spellsuggest",
+            Self::Splitbelow => "splitbelow",
+            Self::Splitkeep => "splitkeep",
+            Self::Splitright => "splitright",
+            Self::Startofline => "startofline",
+            Self::Statuscolumn => "statuscolumn",
+            Self::Statusline => "statusline",
+            Self::Suffixes => "suffixes",
+            Self::Suffixesadd => "suffixesadd",
+            Self::Swapfile => "swapfile",
+            Self::Switchbuf => "switchbuf",
+            Self::Synmaxcol => "synmaxcol",
+            Self::Syntax => "syntax",
+            Self::Tabclose => "tabclose",
+            Self::Tabline => "tabline",
+            Self::Tabpagemax => "tabpagemax",
+            Self::Tabstop => "tabstop",
+            Self::Tagbsearch => "tagbsearch",
+            Self::Tagcase => "tagcase",
+            Self::Tagfunc => "tagfunc",
+            Self::Taglength => "taglength",
+            Self::Tagrelative => "tagrelative",
+            Self::Tags => "tags",
+            Self::Tagstack => "tagstack",
+            Self::Termbidi => "termbidi",
+            Self::Termguicolors => "termguicolors",
+            Self::Termpastefilter => "termpastefilter",
+            Self::Termsync => "termsync",
+            Self::Textwidth => "textwidth",
+            Self::Thesaurus => "thesaurus",
+            Self::Thesaurusfunc => "thesaurusfunc",
+            Self::Tildeop => "tildeop",
+            Self::Timeout => "timeout",
+            Self::Timeoutlen => "timeoutlen",
+            Self::Title => "title",
+            Self::Titlelen => "titlelen",
+            Self::Titleold => "titleold",
+            Self::Titlestring => "titlestring",
+            Self::Ttimeout => "ttimeout",
+            Self::Ttimeoutlen => "ttimeoutlen",
+            Self::Undodir => "undodir",
+            Self::Undofile => "undofile",
+            Self::Undolevels => "undolevels",
+            Self::Undoreload => "undoreload",
+            Self::Updatecount => "updatecount",
+            Self::Updatetime => "updatetime",
+            Self::Varsofttabstop => "varsofttabstop",
+            Self::Vartabstop => "vartabstop",
+            Self::Verbose => "verbose",
+            Self::Verbosefile => "verbosefile",
+            Self::Viewdir => "viewdir",
+            Self::Viewoptions => "viewoptions",
+            Self::Virtualedit => "virtualedit",
+            Self::Visualbell => "visualbell",
+            Self::Warn => "warn",
+            Self::Whichwrap => "whichwrap",
+            Self::Wildchar => "wildchar",
+            Self::Wildcharm => "wildcharm",
+            Self::Wildignore => "wildignore",
+            Self::Wildignorecase => "wildignorecase",
+            Self::Wildmenu => "wildmenu",
+            Self::Wildmode => "wildmode",
+            Self::Wildoptions => "wildoptions",
+            Self::Winaltkeys => "winaltkeys",
+            Self::Winbar => "winbar",
+            Self::Winblend => "winblend",
+            Self::Winborder => "winborder",
+            Self::Window => "window",
+            Self::Winfixbuf => "winfixbuf",
+            Self::Winfixheight => "winfixheight",
+            Self::Winfixwidth => "winfixwidth",
+            Self::Winheight => "winheight",
+            Self::Winhighlight => "winhighlight",
+            Self::Winminheight => "winminheight",
+            Self::Winminwidth => "winminwidth",
+            Self::Winwidth => "winwidth",
+            Self::Wrap => "wrap",
+            Self::Wrapmargin => "wrapmargin",
+            Self::Wrapscan => "wrapscan",
+            Self::Write => "write",
+            Self::Writeany => "writeany",
+            Self::Writebackup => "writebackup",
+            Self::Writedelay => "writedelay",
+        }
+    }
+}
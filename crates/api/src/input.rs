@@ -0,0 +1,202 @@
+//! Functions to read input from the user.
+//!
+//! # Blocking
+//!
+//! **All the functions in this module block until the user types something**,
+//! and Neovim doesn't process any other event while they're waiting. This
+//! freezes the editor: timers, RPC requests, autocommands and scheduled
+//! callbacks all have to wait until the input arrives. Only call them in
+//! response to something the user did, and never from a callback that runs in
+//! the background.
+
+use types::{self as nvim, Array, Object, ObjectKind};
+
+use crate::Error;
+use crate::Result;
+use crate::call_function;
+
+/// A key typed by the user, returned by [`getchar()`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum KeyEvent {
+    /// A key producing a character. This also includes keys like `<Esc>`,
+    /// `<CR>` and `<Tab>`, which are returned as the corresponding control
+    /// character, and keys pressed together with `<C-..>`, e.g. `<C-a>` is
+    /// `'\x01'`.
+    Char(char),
+
+    /// A special key that doesn't produce a character.
+    Special(SpecialKey),
+
+    /// Any other input, e.g. a special key pressed together with a modifier
+    /// or a mouse event, containing the raw bytes returned by Neovim.
+    Other(nvim::String),
+}
+
+/// The special keys that can be returned in a [`KeyEvent`].
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SpecialKey {
+    /// The `<Up>` arrow key.
+    Up,
+
+    /// The `<Down>` arrow key.
+    Down,
+
+    /// The `<Left>` arrow key.
+    Left,
+
+    /// The `<Right>` arrow key.
+    Right,
+
+    /// The `<Home>` key.
+    Home,
+
+    /// The `<End>` key.
+    End,
+
+    /// The `<PageUp>` key.
+    PageUp,
+
+    /// The `<PageDown>` key.
+    PageDown,
+
+    /// The `<Insert>` key.
+    Insert,
+
+    /// The `<Del>` key.
+    Delete,
+
+    /// The `<BS>` key.
+    Backspace,
+
+    /// One of the function keys from `<F1>` to `<F12>`.
+    F(u8),
+}
+
+/// The byte Neovim uses to introduce a special key.
+const K_SPECIAL: u8 = 0x80;
+
+impl KeyEvent {
+    fn from_bytes(bytes: nvim::String) -> Self {
+        if let [K_SPECIAL, a, b] = *bytes.as_bytes() {
+            if let Some(key) = SpecialKey::from_termcap([a, b]) {
+                return Self::Special(key);
+            }
+        }
+
+        if let Ok(str) = bytes.to_str() {
+            let mut chars = str.chars();
+            if let (Some(ch), None) = (chars.next(), chars.next()) {
+                return Self::Char(ch);
+            }
+        }
+
+        Self::Other(bytes)
+    }
+}
+
+impl SpecialKey {
+    /// Decodes the two bytes following [`K_SPECIAL`], which are the key's
+    /// termcap code (see `:h terminal-options`).
+    fn from_termcap(code: [u8; 2]) -> Option<Self> {
+        let key = match &code {
+            b"ku" => Self::Up,
+            b"kd" => Self::Down,
+            b"kl" => Self::Left,
+            b"kr" => Self::Right,
+            b"kh" => Self::Home,
+            b"@7" => Self::End,
+            b"kP" => Self::PageUp,
+            b"kN" => Self::PageDown,
+            b"kI" => Self::Insert,
+            b"kD" => Self::Delete,
+            b"kb" => Self::Backspace,
+            [b'k', n @ b'1'..=b'9'] => Self::F(n - b'0'),
+            b"k;" => Self::F(10),
+            b"F1" => Self::F(11),
+            b"F2" => Self::F(12),
+            _ => return None,
+        };
+        Some(key)
+    }
+}
+
+/// Wrapper around [`getcharstr()`][1].
+///
+/// Waits for the user to type `count` keys and returns the last one, e.g. to
+/// skip over a known prefix. The other keys are discarded, and a `count` of
+/// `0` reads a single key like `1` does. See the
+/// [module-level docs](self#blocking) for why this should be used sparingly.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#getcharstr()
+pub fn getchar(count: u32) -> Result<KeyEvent> {
+    let mut key =
+        call_function::<_, nvim::String>("getcharstr", Array::new())?;
+    for _ in 1..count {
+        key = call_function("getcharstr", Array::new())?;
+    }
+    Ok(KeyEvent::from_bytes(key))
+}
+
+/// Wrapper around [`getchar()`][1].
+///
+/// Waits for the user to type a key and returns the codepoint of the
+/// character it produces. Returns an error if the key is a special key that
+/// doesn't produce a character, in which case [`getchar()`] should be used
+/// instead. See the [module-level docs](self#blocking) for why this should be
+/// used sparingly.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#getchar()
+pub fn get_char_nr() -> Result<u32> {
+    let obj = call_function::<_, Object>("getchar", Array::new())?;
+
+    match obj.kind() {
+        ObjectKind::Integer => {
+            let nr = unsafe { obj.as_integer_unchecked() };
+            u32::try_from(nr).map_err(Into::into)
+        },
+        _ => Err(Error::custom(format!(
+            "the typed key doesn't produce a character: {obj:?}"
+        ))),
+    }
+}
+
+/// Wrapper around [`inputlist()`][1].
+///
+/// Shows the lines in `textlist` and asks the user to enter a number, which
+/// is returned. The first line is usually a prompt, and the following ones
+/// the items to choose from, starting with `"1. "`. Returns 0 if the user
+/// cancels the prompt, and the number may be out of range of `textlist`. See
+/// the [module-level docs](self#blocking) for why this should be used
+/// sparingly.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#inputlist()
+pub fn input_list(textlist: &[&str]) -> Result<u32> {
+    let textlist = textlist.iter().copied().collect::<Array>();
+    call_function("inputlist", (textlist,))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_event_from_bytes() {
+        let key = |bytes: &[u8]| {
+            KeyEvent::from_bytes(nvim::String::from_bytes(bytes))
+        };
+
+        assert_eq!(key(b"a"), KeyEvent::Char('a'));
+        assert_eq!(key("é".as_bytes()), KeyEvent::Char('é'));
+        assert_eq!(key(b"\x1b"), KeyEvent::Char('\x1b'));
+        assert_eq!(key(b"\x80kl"), KeyEvent::Special(SpecialKey::Left));
+        assert_eq!(key(b"\x80k5"), KeyEvent::Special(SpecialKey::F(5)));
+        assert_eq!(key(b"\x80F2"), KeyEvent::Special(SpecialKey::F(12)));
+        assert_eq!(
+            key(b"\x80zz"),
+            KeyEvent::Other(nvim::String::from_bytes(b"\x80zz"))
+        );
+        assert_eq!(key(b"ab"), KeyEvent::Other("ab".into()));
+    }
+}
@@ -10,39 +10,66 @@
 //! Also, the functions starting with `nvim_buf_*`, `nvim_win_*` and
 //! `nvim_tabpage_*` are implemented as methods on the [`Buffer`], [`Window`]
 //! and [`TabPage`] objects respectively.
+//!
+//! A few of Vimscript's builtin functions that don't have an API equivalent,
+//! like [`bufnr`], are also exposed under their original name.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod autocmd;
+mod buf_line_iter;
 mod buffer;
+mod builtin;
+mod call_context;
+pub mod color;
 mod command;
+mod command_builder;
 mod deprecated;
+pub mod diagnostic;
 mod error;
+mod event_emitter;
 mod extmark;
+pub mod features;
 mod ffi;
+mod generated;
+pub mod input;
+pub mod lsp;
+pub mod marks;
 mod options;
 pub mod opts;
+#[cfg(feature = "ropey")]
+mod rope;
+pub mod rpc;
 pub(crate) mod serde_utils;
+pub mod spell;
 mod tabpage;
 mod trait_utils;
 pub mod types;
+pub mod ui;
 pub(crate) mod utils;
+mod var_watch;
 mod vim;
 mod vimscript;
 mod win_config;
 mod window;
 
 pub use autocmd::*;
+pub use buf_line_iter::*;
 pub use buffer::*;
+pub use builtin::*;
+pub use call_context::*;
 pub use command::*;
+pub use command_builder::*;
 pub use deprecated::*;
 pub use error::Error;
 use error::Result;
+pub use event_emitter::*;
 pub use extmark::*;
 pub use luajit::IntoResult;
 pub use options::*;
 pub use tabpage::*;
 pub use trait_utils::*;
+pub use var_watch::*;
 pub use vim::*;
 pub use vimscript::*;
 pub use win_config::*;
@@ -0,0 +1,151 @@
+//! Functions to interact with the language servers attached to a buffer,
+//! built on top of Neovim's [`vim.lsp`][1] Lua module.
+//!
+//! [1]: https://neovim.io/doc/user/lsp.html#lsp-core
+
+use std::collections::HashMap;
+
+use luajit::{self as lua, Poppable, Pushable};
+use serde::Deserialize;
+use types::{
+    Function,
+    Object,
+    conversion::{self, FromObject},
+    serde::Deserializer,
+};
+
+use crate::Buffer;
+use crate::Result;
+
+/// An error returned by a language server in response to a request.
+///
+/// See the [LSP specification][1] for the meaning of the fields.
+///
+/// [1]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#responseMessage
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct LspError {
+    /// The error code, e.g. `-32601` for `MethodNotFound`.
+    pub code: i64,
+
+    /// A short description of the error.
+    pub message: String,
+
+    /// Additional infos about the error, if any.
+    #[serde(default)]
+    pub data: Option<Object>,
+}
+
+impl FromObject for LspError {
+    fn from_object(
+        obj: Object,
+    ) -> std::result::Result<Self, conversion::Error> {
+        Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
+    }
+}
+
+impl Poppable for LspError {
+    unsafe fn pop(
+        lstate: *mut lua::ffi::State,
+    ) -> std::result::Result<Self, lua::Error> {
+        let obj = unsafe { Object::pop(lstate) }?;
+
+        Self::from_object(obj)
+            .map_err(lua::Error::pop_error_from_err::<Self, _>)
+    }
+}
+
+/// A handle to a request sent by [`buf_request()`] to a single language
+/// server, which can be used to cancel it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LspRequestCancelFn {
+    client_id: u32,
+    request_id: i32,
+}
+
+impl LspRequestCancelFn {
+    /// Returns the id of the language server the request was sent to.
+    #[inline]
+    pub fn client_id(&self) -> u32 {
+        self.client_id
+    }
+
+    /// Returns the id of the request.
+    #[inline]
+    pub fn request_id(&self) -> i32 {
+        self.request_id
+    }
+
+    /// Cancels the request. Does nothing if the language server has already
+    /// replied or has been stopped.
+    pub fn cancel(self) -> Result<()> {
+        #[cfg(not(feature = "neovim-0-11"))] // Only on 0.10.
+        const CANCEL: &str = r#"
+            local client_id, request_id = ...
+            local client = vim.lsp.get_client_by_id(client_id)
+            if client then client.cancel_request(request_id) end
+        "#;
+
+        #[cfg(feature = "neovim-0-11")] // On 0.11 and Nightly.
+        const CANCEL: &str = r#"
+            local client_id, request_id = ...
+            local client = vim.lsp.get_client_by_id(client_id)
+            if client then client:cancel_request(request_id) end
+        "#;
+
+        crate::exec_lua(CANCEL, (self.client_id, self.request_id))
+    }
+}
+
+/// Binding to [`vim.lsp.buf_request()`][1].
+///
+/// Sends a request with the given `method` and `params` to all the language
+/// servers attached to `buf` that support it. The `handler` is called once
+/// for every server that replies, with either the error returned by the
+/// server or the result of the request.
+///
+/// Returns a map from the id of every server the request was sent to to a
+/// handle that can be used to cancel it.
+///
+/// [1]: https://neovim.io/doc/user/lsp.html#vim.lsp.buf_request()
+pub fn buf_request<P, F>(
+    buf: &Buffer,
+    method: &str,
+    params: P,
+    handler: F,
+) -> Result<HashMap<u32, LspRequestCancelFn>>
+where
+    P: Pushable,
+    F: Fn(Option<LspError>, Object) + 'static,
+{
+    const BUF_REQUEST: &str = r#"
+        local buf, method, params, handler = ...
+        local client_request_ids = vim.lsp.buf_request(
+            buf,
+            method,
+            params,
+            function(err, result) handler(err, result) end
+        )
+        return client_request_ids
+    "#;
+
+    let handler = Function::<(Option<LspError>, Object), ()>::from_fn(
+        move |(err, result)| handler(err, result),
+    );
+
+    let client_request_ids = crate::exec_lua::<_, HashMap<u32, i32>>(
+        BUF_REQUEST,
+        (buf.clone(), method.to_owned(), params, handler.clone()),
+    );
+
+    // The Lua closure passed to `vim.lsp.buf_request()` keeps the handler
+    // alive, so we don't need to keep it in the registry.
+    handler.remove_from_lua_registry();
+
+    Ok(client_request_ids?
+        .into_iter()
+        .map(|(client_id, request_id)| {
+            (client_id, LspRequestCancelFn { client_id, request_id })
+        })
+        .collect())
+}
@@ -0,0 +1,184 @@
+//! Functions to get, set and delete marks.
+//!
+//! Neovim's API has separate functions for buffer-local and global marks.
+//! The functions in this module pick the right one based on the name of the
+//! mark, which is classified into a [`MarkKind`]:
+//!
+//! - [local](MarkKind::Local) marks are the lowercase letters `a-z`, which
+//!   are local to a buffer;
+//!
+//! - [global](MarkKind::Global) marks are the uppercase letters `A-Z`, which
+//!   are set by the user, and the digits `0-9`, which are set by Neovim from
+//!   the ShaDa file;
+//!
+//! - [special](MarkKind::Special) marks are the ones listed in
+//!   [`SPECIAL_MARKS`], which are set automatically by Neovim and are also
+//!   local to a buffer.
+//!
+//! All the functions return an error if the name isn't a valid mark name.
+//! Marks are (1,0)-indexed.
+
+use crate::Buffer;
+use crate::Error;
+use crate::Result;
+use crate::opts::SetMarkOpts;
+
+/// The special marks supported by this module.
+///
+/// See `:h mark-motions` for what each of these marks refers to.
+pub const SPECIAL_MARKS: [char; 8] = ['\'', '"', '[', ']', '^', '.', '<', '>'];
+
+/// The category a mark belongs to, based on its name.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MarkKind {
+    /// A lowercase mark local to a buffer, e.g. `'a`.
+    Local,
+
+    /// An uppercase or numbered mark, e.g. `'A` or `'0`.
+    Global,
+
+    /// A mark set automatically by Neovim, e.g. `'.`.
+    Special,
+}
+
+impl MarkKind {
+    /// Returns the kind of the mark with the given name, or `None` if the name
+    /// isn't a valid mark name.
+    #[inline]
+    pub fn of(name: char) -> Option<Self> {
+        match name {
+            'a'..='z' => Some(Self::Local),
+            'A'..='Z' | '0'..='9' => Some(Self::Global),
+            _ if SPECIAL_MARKS.contains(&name) => Some(Self::Special),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn try_of(name: char) -> Result<Self> {
+        Self::of(name).ok_or_else(|| {
+            Error::custom(format!(
+                "{name:?} is not a valid mark name: expected a letter, a \
+                 digit or one of {SPECIAL_MARKS:?}"
+            ))
+        })
+    }
+}
+
+/// The position of a mark.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Mark {
+    /// The buffer the mark is in.
+    pub buffer: Buffer,
+
+    /// The 1-indexed line of the mark.
+    pub row: usize,
+
+    /// The 0-indexed byte column of the mark.
+    pub col: usize,
+}
+
+/// Returns the position of the mark with the given name, or `None` if the
+/// mark isn't set.
+///
+/// Local and special marks are looked up in the current buffer. Returns an
+/// error if `name` is a global mark in a file that isn't loaded in any buffer.
+pub fn get(name: char) -> Result<Option<Mark>> {
+    match MarkKind::try_of(name)? {
+        MarkKind::Local | MarkKind::Special => {
+            get_local(&Buffer::current(), name)
+        },
+        MarkKind::Global => get_global(name),
+    }
+}
+
+/// Sets the mark with the given name to `mark`.
+///
+/// Local and special marks are set in `mark.buffer`. Returns an error if
+/// `name` is a numbered mark, since those can only be set by Neovim.
+pub fn set(name: char, mark: Mark) -> Result<()> {
+    if MarkKind::try_of(name)? == MarkKind::Global && name.is_ascii_digit() {
+        return Err(Error::custom(format!(
+            "mark {name:?} is a numbered mark and can only be set by Neovim"
+        )));
+    }
+    let Mark { mut buffer, row, col } = mark;
+    buffer.set_mark(name, row, col, &SetMarkOpts::default())
+}
+
+/// Deletes the mark with the given name.
+///
+/// Local and special marks are deleted from the current buffer.
+pub fn del(name: char) -> Result<()> {
+    match MarkKind::try_of(name)? {
+        MarkKind::Local | MarkKind::Special => {
+            Buffer::current().del_mark(name)
+        },
+        MarkKind::Global => crate::del_mark(name),
+    }
+}
+
+/// Returns all the marks that are set in the given buffer, in the order
+/// local, global and special.
+pub fn get_buf_marks(buf: &Buffer) -> Result<Vec<(char, Mark)>> {
+    let mut marks = Vec::new();
+
+    for name in 'a'..='z' {
+        if let Some(mark) = get_local(buf, name)? {
+            marks.push((name, mark));
+        }
+    }
+
+    for name in ('A'..='Z').chain('0'..='9') {
+        // Global marks in files that aren't loaded can't be in `buf`.
+        if let Ok(Some(mark)) = get_global(name) {
+            if mark.buffer == *buf {
+                marks.push((name, mark));
+            }
+        }
+    }
+
+    for name in SPECIAL_MARKS {
+        if let Some(mark) = get_local(buf, name)? {
+            marks.push((name, mark));
+        }
+    }
+
+    Ok(marks)
+}
+
+fn get_local(buf: &Buffer, name: char) -> Result<Option<Mark>> {
+    let (row, col) = buf.get_mark(name)?;
+    Ok((row != 0).then(|| Mark { buffer: buf.clone(), row, col }))
+}
+
+fn get_global(name: char) -> Result<Option<Mark>> {
+    let (row, col, buffer, file) = crate::get_mark(name, &Default::default())?;
+
+    if row == 0 {
+        return Ok(None);
+    }
+
+    if buffer.handle() == 0 {
+        return Err(Error::custom(format!(
+            "mark {name:?} is in {file:?}, which isn't loaded in any buffer"
+        )));
+    }
+
+    Ok(Some(Mark { buffer, row, col }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mark_kind_of() {
+        assert_eq!(MarkKind::of('a'), Some(MarkKind::Local));
+        assert_eq!(MarkKind::of('Z'), Some(MarkKind::Global));
+        assert_eq!(MarkKind::of('0'), Some(MarkKind::Global));
+        assert_eq!(MarkKind::of('.'), Some(MarkKind::Special));
+        assert_eq!(MarkKind::of('!'), None);
+        assert_eq!(MarkKind::of('é'), None);
+    }
+}
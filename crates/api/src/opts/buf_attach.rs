@@ -29,6 +29,67 @@ pub type OnLinesArgs = (
     Option<usize>,
 );
 
+/// The arguments of the [`on_lines`](BufAttachOptsBuilder::on_lines)
+/// callback with named fields, passed to the callback given to
+/// [`BufAttachOpts::on_lines()`].
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BufLinesEvent {
+    /// The buffer that changed.
+    pub buffer: Buffer,
+
+    /// The value of the buffer-local `b:changedtick` variable.
+    pub changedtick: u32,
+
+    /// The first row that changed (0-indexed).
+    pub first_line: usize,
+
+    /// The last row that was changed, before the change.
+    pub last_line: usize,
+
+    /// The last row in the updated range, after the change.
+    pub new_last_line: usize,
+
+    /// The byte count of the previous contents.
+    pub prev_byte_count: usize,
+
+    /// The deleted UTF-32 codepoints, if
+    /// [`utf_sizes`](BufAttachOptsBuilder::utf_sizes) was `true`.
+    pub deleted_codepoints: Option<usize>,
+
+    /// The deleted UTF-16 codeunits, if
+    /// [`utf_sizes`](BufAttachOptsBuilder::utf_sizes) was `true`.
+    pub deleted_codeunits: Option<usize>,
+}
+
+impl From<OnLinesArgs> for BufLinesEvent {
+    #[inline]
+    fn from(args: OnLinesArgs) -> Self {
+        let (
+            _,
+            buffer,
+            changedtick,
+            first_line,
+            last_line,
+            new_last_line,
+            prev_byte_count,
+            deleted_codepoints,
+            deleted_codeunits,
+        ) = args;
+
+        Self {
+            buffer,
+            changedtick,
+            first_line,
+            last_line,
+            new_last_line,
+            prev_byte_count,
+            deleted_codepoints,
+            deleted_codeunits,
+        }
+    }
+}
+
 /// Arguments passed to the callback registered to [`on_bytes`](BufAttachOptsBuilder::on_bytes).
 ///
 /// The `(a, b, c, d, e, f, g, h, i, j, k, l)`
@@ -148,3 +209,42 @@ pub struct BufAttachOpts {
     #[builder(argtype = "bool")]
     preview: types::Boolean,
 }
+
+impl BufAttachOpts {
+    /// Shorthand for options that only register an
+    /// [`on_lines`](BufAttachOptsBuilder::on_lines) callback, which is called
+    /// with a [`BufLinesEvent`] on every change and never detaches.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let opts = BufAttachOpts::on_lines(|event| {
+    ///     nvim_oxi::print!(
+    ///         "lines {}..{} changed",
+    ///         event.first_line,
+    ///         event.new_last_line
+    ///     );
+    /// });
+    ///
+    /// Buffer::current().attach(false, &opts)?;
+    /// ```
+    #[inline]
+    pub fn on_lines<F>(mut on_lines: F) -> Self
+    where
+        F: FnMut(BufLinesEvent) + 'static,
+    {
+        Self::builder()
+            .on_lines(move |args: OnLinesArgs| {
+                on_lines(args.into());
+                false
+            })
+            .build()
+    }
+
+    /// Returns the reference to the `on_lines` callback, if one was
+    /// registered.
+    #[inline]
+    pub(crate) fn on_lines_ref(&self) -> Option<types::LuaRef> {
+        (self.on_lines > 0).then_some(self.on_lines)
+    }
+}
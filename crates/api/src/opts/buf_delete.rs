@@ -1,15 +1,16 @@
-/// Options passed to [`Buffer::attach`](crate::Buffer::attach).
+/// Options passed to [`Buffer::delete`](crate::Buffer::delete).
 #[derive(Clone, Debug, Default, macros::OptsBuilder)]
 #[repr(C)]
 pub struct BufDeleteOpts {
     #[builder(mask)]
     mask: u64,
 
-    /// Force deletion ignoring unsaved changes.
+    /// Force deletion, discarding any unsaved changes.
     #[builder(argtype = "bool")]
     force: types::Boolean,
 
-    /// If `true` the buffer will only be unloaded, not deleted.
+    /// If `true` the buffer will only be unloaded (like `:bunload`), clearing
+    /// its contents but keeping its handle valid.
     #[builder(argtype = "bool")]
     unload: types::Boolean,
 }
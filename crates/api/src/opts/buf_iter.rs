@@ -0,0 +1,37 @@
+/// Options passed to [`Buffer::iter_lines()`](crate::Buffer::iter_lines).
+#[derive(Clone, Debug)]
+pub struct BufIterOpts {
+    pub(crate) batch_size: usize,
+}
+
+impl Default for BufIterOpts {
+    #[inline]
+    fn default() -> Self {
+        Self { batch_size: 1024 }
+    }
+}
+
+impl BufIterOpts {
+    #[inline(always)]
+    pub fn builder() -> BufIterOptsBuilder {
+        BufIterOptsBuilder::default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct BufIterOptsBuilder(BufIterOpts);
+
+impl BufIterOptsBuilder {
+    /// The number of lines fetched from Neovim at a time. Defaults to 1024.
+    /// A batch size of 0 is treated as 1.
+    #[inline]
+    pub fn batch_size(&mut self, batch_size: usize) -> &mut Self {
+        self.0.batch_size = batch_size.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> BufIterOpts {
+        std::mem::take(&mut self.0)
+    }
+}
@@ -1,6 +1,6 @@
 use crate::Buffer;
 use crate::StringOrInt;
-use crate::types::AutocmdCallbackArgs;
+use crate::types::{AutocmdCallbackArgs, AutocmdPattern};
 
 pub type ShouldDeleteAutocmd = bool;
 
@@ -59,12 +59,21 @@ pub struct CreateAutocmdOpts {
     #[builder(argtype = "bool")]
     once: types::Boolean,
 
-    /// Patterns to match against.
+    /// Patterns to match against. Strings like `"<buffer>"` and
+    /// `"<buffer=N>"` are parsed as buffer-local patterns.
     #[builder(
-        generics = "'a, I: IntoIterator<Item = &'a str>",
+        generics = "P: Into<AutocmdPattern>, I: IntoIterator<Item = P>",
         method = "patterns",
         argtype = "I",
-        inline = "types::Array::from_iter({0}).into()"
+        inline = "patterns_to_object({0})"
     )]
     pattern: types::Object,
 }
+
+fn patterns_to_object<P, I>(patterns: I) -> types::Object
+where
+    P: Into<AutocmdPattern>,
+    I: IntoIterator<Item = P>,
+{
+    patterns.into_iter().map(Into::into).collect::<types::Array>().into()
+}
@@ -6,6 +6,7 @@ use crate::types::{
     CommandArgs,
     CommandComplete,
     CommandNArgs,
+    CommandPreview,
     CommandRange,
 };
 
@@ -57,8 +58,12 @@ pub struct CreateCommandOpts {
     #[builder(argtype = "CommandNArgs", inline = "{0}.to_object().unwrap()")]
     nargs: types::Object,
 
+    /// Callback used to preview the command while it's being typed. It's
+    /// called with the arguments of the command, the namespace to add
+    /// highlights to and the preview buffer, which is `None` unless
+    /// `'inccommand'` is set to `"split"`.
     #[builder(
-        generics = r#"F: Into<types::Function<(CommandArgs, Option<u32>, Option<Buffer>), u8>>"#,
+        generics = r#"F: Into<types::Function<(CommandArgs, Option<u32>, Option<Buffer>), CommandPreview>>"#,
         argtype = "F",
         inline = "{0}.into().into()"
     )]
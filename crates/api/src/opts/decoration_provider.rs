@@ -32,7 +32,7 @@ pub type OnWinArgs = (
 /// Arguments passed to the function registered to
 /// [`on_line`](DecorationProviderOptsBuilder::on_line).
 pub type OnLineArgs = (
-    String, // the string literal "win"
+    String, // the string literal "line"
     Window, // window
     Buffer, // buffer
     usize,  // row
@@ -89,6 +89,9 @@ pub struct DecorationProviderOpts {
     )]
     on_line: LuaRef,
 
+    /// Called at the end of every redraw cycle, after all the other
+    /// callbacks. This is the place to clean up any state set up by them,
+    /// e.g. temporary extmarks.
     #[builder(
         generics = "F: ToFunction<OnEndArgs, ()>",
         argtype = "F",
@@ -0,0 +1,45 @@
+use types::Dictionary;
+
+/// Options passed to [`diagnostic::enable()`](crate::diagnostic::enable) and
+/// [`diagnostic::disable()`](crate::diagnostic::disable).
+#[derive(Clone, Debug, Default)]
+pub struct DiagnosticEnableOpts {
+    pub(crate) ns_id: Option<u32>,
+}
+
+impl DiagnosticEnableOpts {
+    #[inline(always)]
+    pub fn builder() -> DiagnosticEnableOptsBuilder {
+        DiagnosticEnableOptsBuilder::default()
+    }
+
+    /// Converts the options into the filter passed to
+    /// `vim.diagnostic.enable()`, leaving out the ones that weren't set.
+    pub(crate) fn to_dict(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+
+        if let Some(ns_id) = self.ns_id {
+            dict.insert("ns_id", ns_id);
+        }
+
+        dict
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct DiagnosticEnableOptsBuilder(DiagnosticEnableOpts);
+
+impl DiagnosticEnableOptsBuilder {
+    /// Only toggle the diagnostics in the namespace with this id. By default
+    /// the diagnostics of all namespaces are toggled.
+    #[inline]
+    pub fn ns_id(&mut self, ns_id: u32) -> &mut Self {
+        self.0.ns_id = Some(ns_id);
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> DiagnosticEnableOpts {
+        std::mem::take(&mut self.0)
+    }
+}
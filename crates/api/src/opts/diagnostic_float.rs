@@ -0,0 +1,223 @@
+use types::{Dictionary, Function, Object};
+
+use crate::types::{DiagnosticSeverity, WindowBorder};
+
+/// Options passed to
+/// [`diagnostic::open_float()`](crate::diagnostic::open_float).
+#[derive(Clone, Debug, Default)]
+pub struct DiagnosticFloatOpts {
+    pub(crate) namespace: Option<u32>,
+    pub(crate) scope: Option<DiagnosticScope>,
+    pub(crate) pos: Option<(usize, usize)>,
+    pub(crate) severity: Option<DiagnosticSeverity>,
+    pub(crate) header: Option<String>,
+    pub(crate) source: Option<DiagnosticSource>,
+    pub(crate) format: Option<Function<Dictionary, Option<String>>>,
+    pub(crate) border: Option<WindowBorder>,
+    pub(crate) focusable: Option<bool>,
+    pub(crate) max_width: Option<u32>,
+    pub(crate) max_height: Option<u32>,
+    pub(crate) wrap: Option<bool>,
+}
+
+/// Which diagnostics to show in the float.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DiagnosticScope {
+    /// All the diagnostics in the buffer.
+    Buffer,
+
+    /// The diagnostics on the current line.
+    Line,
+
+    /// The diagnostics under the cursor.
+    Cursor,
+}
+
+/// Whether to show the source of the diagnostics in the float.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DiagnosticSource {
+    /// Always show the source.
+    Always,
+
+    /// Only show the source if there's more than one source among the shown
+    /// diagnostics.
+    IfMany,
+
+    /// Never show the source.
+    Never,
+}
+
+impl DiagnosticFloatOpts {
+    #[inline(always)]
+    pub fn builder() -> DiagnosticFloatOptsBuilder {
+        DiagnosticFloatOptsBuilder::default()
+    }
+
+    /// Converts the options into the table passed to
+    /// `vim.diagnostic.open_float()`, leaving out the ones that weren't set.
+    pub(crate) fn to_dict(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+
+        if let Some(namespace) = self.namespace {
+            dict.insert("namespace", namespace);
+        }
+        if let Some(scope) = self.scope {
+            dict.insert("scope", scope.as_str());
+        }
+        if let Some((row, col)) = self.pos {
+            let pos = [row, col].map(|n| n as types::Integer);
+            dict.insert("pos", types::Array::from_iter(pos));
+        }
+        if let Some(severity) = self.severity {
+            dict.insert("severity", severity);
+        }
+        if let Some(header) = &self.header {
+            dict.insert("header", header.as_str());
+        }
+        if let Some(source) = self.source {
+            dict.insert("source", Object::from(source));
+        }
+        if let Some(format) = &self.format {
+            dict.insert("format", format.clone());
+        }
+        if let Some(border) = &self.border {
+            dict.insert("border", border.clone());
+        }
+        if let Some(focusable) = self.focusable {
+            dict.insert("focusable", focusable);
+        }
+        if let Some(max_width) = self.max_width {
+            dict.insert("max_width", max_width);
+        }
+        if let Some(max_height) = self.max_height {
+            dict.insert("max_height", max_height);
+        }
+        if let Some(wrap) = self.wrap {
+            dict.insert("wrap", wrap);
+        }
+
+        dict
+    }
+}
+
+impl DiagnosticScope {
+    #[inline]
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Buffer => "buffer",
+            Self::Line => "line",
+            Self::Cursor => "cursor",
+        }
+    }
+}
+
+impl From<DiagnosticSource> for Object {
+    #[inline]
+    fn from(source: DiagnosticSource) -> Self {
+        match source {
+            DiagnosticSource::Always => true.into(),
+            DiagnosticSource::IfMany => "if_many".into(),
+            DiagnosticSource::Never => false.into(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct DiagnosticFloatOptsBuilder(DiagnosticFloatOpts);
+
+impl DiagnosticFloatOptsBuilder {
+    /// Only show the diagnostics in the namespace with this id.
+    #[inline]
+    pub fn namespace(&mut self, ns_id: u32) -> &mut Self {
+        self.0.namespace = Some(ns_id);
+        self
+    }
+
+    /// Which diagnostics to show. Defaults to [`DiagnosticScope::Line`].
+    #[inline]
+    pub fn scope(&mut self, scope: DiagnosticScope) -> &mut Self {
+        self.0.scope = Some(scope);
+        self
+    }
+
+    /// The 0-indexed `(row, col)` position to use instead of the cursor
+    /// position when the scope is [`Line`](DiagnosticScope::Line) or
+    /// [`Cursor`](DiagnosticScope::Cursor).
+    #[inline]
+    pub fn pos(&mut self, row: usize, col: usize) -> &mut Self {
+        self.0.pos = Some((row, col));
+        self
+    }
+
+    /// Only show the diagnostics with this severity.
+    #[inline]
+    pub fn severity(&mut self, severity: DiagnosticSeverity) -> &mut Self {
+        self.0.severity = Some(severity);
+        self
+    }
+
+    /// The first line of the float, `"Diagnostics:"` by default.
+    #[inline]
+    pub fn header(&mut self, header: impl Into<String>) -> &mut Self {
+        self.0.header = Some(header.into());
+        self
+    }
+
+    /// Whether to show the source of the diagnostics.
+    #[inline]
+    pub fn source(&mut self, source: DiagnosticSource) -> &mut Self {
+        self.0.source = Some(source);
+        self
+    }
+
+    /// A function called with each diagnostic, as a table, returning the text
+    /// to show for it. If it returns `None` the diagnostic isn't shown.
+    #[inline]
+    pub fn format<F>(&mut self, format: F) -> &mut Self
+    where
+        F: Into<Function<Dictionary, Option<String>>>,
+    {
+        self.0.format = Some(format.into());
+        self
+    }
+
+    /// The border of the float.
+    #[inline]
+    pub fn border(&mut self, border: WindowBorder) -> &mut Self {
+        self.0.border = Some(border);
+        self
+    }
+
+    /// Whether the float can be focused.
+    #[inline]
+    pub fn focusable(&mut self, focusable: bool) -> &mut Self {
+        self.0.focusable = Some(focusable);
+        self
+    }
+
+    /// The maximum width of the float.
+    #[inline]
+    pub fn max_width(&mut self, max_width: u32) -> &mut Self {
+        self.0.max_width = Some(max_width);
+        self
+    }
+
+    /// The maximum height of the float.
+    #[inline]
+    pub fn max_height(&mut self, max_height: u32) -> &mut Self {
+        self.0.max_height = Some(max_height);
+        self
+    }
+
+    /// Whether to wrap long lines.
+    #[inline]
+    pub fn wrap(&mut self, wrap: bool) -> &mut Self {
+        self.0.wrap = Some(wrap);
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> DiagnosticFloatOpts {
+        std::mem::take(&mut self.0)
+    }
+}
@@ -35,6 +35,8 @@ pub struct ExecAutocmdsOpts {
     )]
     pattern: types::Object,
 
+    /// Arbitrary data to pass to the callbacks of the matching autocommands,
+    /// available as [`AutocmdCallbackArgs::data`](crate::types::AutocmdCallbackArgs::data).
     #[builder(
         generics = "D: Into<types::Object>",
         argtype = "D",
@@ -0,0 +1,29 @@
+/// Options passed to [`get_messages()`](crate::get_messages).
+#[derive(Clone, Debug, Default)]
+pub struct GetMessagesOpts {
+    pub(crate) count: Option<u32>,
+}
+
+impl GetMessagesOpts {
+    #[inline(always)]
+    pub fn builder() -> GetMessagesOptsBuilder {
+        GetMessagesOptsBuilder::default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct GetMessagesOptsBuilder(GetMessagesOpts);
+
+impl GetMessagesOptsBuilder {
+    /// Only return the `count` most recent messages (like `:{count}messages`).
+    #[inline]
+    pub fn count(&mut self, count: u32) -> &mut Self {
+        self.0.count = Some(count);
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> GetMessagesOpts {
+        std::mem::take(&mut self.0)
+    }
+}
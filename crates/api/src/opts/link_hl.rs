@@ -0,0 +1,39 @@
+/// Options passed to [`link_hl()`](crate::link_hl).
+#[derive(Clone, Debug, Default)]
+pub struct LinkHlOpts {
+    pub(crate) force: bool,
+    pub(crate) ns_id: u32,
+}
+
+impl LinkHlOpts {
+    #[inline(always)]
+    pub fn builder() -> LinkHlOptsBuilder {
+        LinkHlOptsBuilder::default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct LinkHlOptsBuilder(LinkHlOpts);
+
+impl LinkHlOptsBuilder {
+    /// Whether to update the group even if it already exists, replacing any
+    /// attributes it defines.
+    #[inline]
+    pub fn force(&mut self, force: bool) -> &mut Self {
+        self.0.force = force;
+        self
+    }
+
+    /// The namespace to define the link in. Defaults to 0, the global
+    /// namespace.
+    #[inline]
+    pub fn ns_id(&mut self, ns_id: u32) -> &mut Self {
+        self.0.ns_id = ns_id;
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> LinkHlOpts {
+        std::mem::take(&mut self.0)
+    }
+}
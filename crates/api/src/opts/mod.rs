@@ -3,12 +3,15 @@
 
 mod buf_attach;
 mod buf_delete;
+mod buf_iter;
 mod clear_autocmds;
 mod cmd;
 mod create_augroup;
 mod create_autocmd;
 mod create_command;
 mod decoration_provider;
+mod diagnostic_enable;
+mod diagnostic_float;
 mod echo;
 mod eval_statusline;
 mod exec;
@@ -20,8 +23,10 @@ mod get_extmark_by_id;
 mod get_extmarks;
 mod get_highlight;
 mod get_mark;
+mod get_messages;
 mod get_namespace;
 mod get_text;
+mod link_hl;
 mod open_term;
 mod option;
 mod parse_cmd;
@@ -30,16 +35,21 @@ mod set_extmark;
 mod set_highlight;
 mod set_keymap;
 mod set_mark;
+mod split;
+mod virt_text;
 mod win_text_height;
 
 pub use buf_attach::*;
 pub use buf_delete::*;
+pub use buf_iter::*;
 pub use clear_autocmds::*;
 pub use cmd::*;
 pub use create_augroup::*;
 pub use create_autocmd::*;
 pub use create_command::*;
 pub use decoration_provider::*;
+pub use diagnostic_enable::*;
+pub use diagnostic_float::*;
 pub use echo::*;
 pub use eval_statusline::*;
 pub use exec::*;
@@ -51,8 +61,10 @@ pub use get_extmark_by_id::*;
 pub use get_extmarks::*;
 pub use get_highlight::*;
 pub use get_mark::*;
+pub use get_messages::*;
 pub use get_namespace::*;
 pub use get_text::*;
+pub use link_hl::*;
 pub use open_term::*;
 pub use option::*;
 pub use parse_cmd::*;
@@ -61,4 +73,6 @@ pub use set_extmark::*;
 pub use set_highlight::*;
 pub use set_keymap::*;
 pub use set_mark::*;
+pub use split::*;
+pub use virt_text::*;
 pub use win_text_height::*;
@@ -53,25 +53,45 @@ pub struct SetHighlightOpts {
     #[builder(argtype = "&str", inline = "types::String::from({0}).into()")]
     cterm: Object,
 
-    #[builder(argtype = "&str", inline = "types::String::from({0}).into()")]
+    #[builder(
+        generics = "C: Into<crate::color::Color>",
+        argtype = "C",
+        inline = r#"{ let Some(color) = crate::color::Color::into_gui_object({0}.into()) else { return self; }; color }"#
+    )]
     foreground: Object,
 
     #[builder(skip)]
     fg: Object,
 
-    #[builder(argtype = "&str", inline = "types::String::from({0}).into()")]
+    #[builder(
+        generics = "C: Into<crate::color::Color>",
+        argtype = "C",
+        inline = r#"{ let Some(color) = crate::color::Color::into_gui_object({0}.into()) else { return self; }; color }"#
+    )]
     background: Object,
 
     #[builder(skip)]
     bg: Object,
 
-    #[builder(argtype = "&str", inline = "types::String::from({0}).into()")]
+    #[builder(
+        generics = "C: Into<crate::color::Color>",
+        argtype = "C",
+        inline = r#"{ let Some(color) = crate::color::Color::into_cterm_object({0}.into()) else { return self; }; color }"#
+    )]
     ctermfg: Object,
 
-    #[builder(argtype = "&str", inline = "types::String::from({0}).into()")]
+    #[builder(
+        generics = "C: Into<crate::color::Color>",
+        argtype = "C",
+        inline = r#"{ let Some(color) = crate::color::Color::into_cterm_object({0}.into()) else { return self; }; color }"#
+    )]
     ctermbg: Object,
 
-    #[builder(argtype = "&str", inline = "types::String::from({0}).into()")]
+    #[builder(
+        generics = "C: Into<crate::color::Color>",
+        argtype = "C",
+        inline = r#"{ let Some(color) = crate::color::Color::into_gui_object({0}.into()) else { return self; }; color }"#
+    )]
     special: Object,
 
     #[builder(skip)]
@@ -0,0 +1,52 @@
+use crate::types::SplitModifier;
+
+/// Options passed to [`split()`](crate::split).
+#[derive(Clone, Debug, Default)]
+pub struct SplitOpts {
+    pub(crate) mods: Option<SplitModifier>,
+    pub(crate) new: bool,
+    pub(crate) size: Option<u32>,
+}
+
+impl SplitOpts {
+    #[inline(always)]
+    pub fn builder() -> SplitOptsBuilder {
+        SplitOptsBuilder::default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct SplitOptsBuilder(SplitOpts);
+
+impl SplitOptsBuilder {
+    /// The split modifier to use. It takes precedence over the one implied
+    /// by the [`SplitCommand`](crate::types::SplitCommand), e.g. it can be
+    /// set to [`SplitModifier::TopLeft`] to create a full-width split at the
+    /// top of the screen.
+    #[inline]
+    pub fn mods(&mut self, mods: SplitModifier) -> &mut Self {
+        self.0.mods = Some(mods);
+        self
+    }
+
+    /// Whether to edit a new empty buffer in the split (like `:new` and
+    /// `:vnew`). Ignored if a buffer is passed to [`split()`](crate::split).
+    #[inline]
+    pub fn new(&mut self, new: bool) -> &mut Self {
+        self.0.new = new;
+        self
+    }
+
+    /// The height of the new window for horizontal splits, or its width for
+    /// vertical ones.
+    #[inline]
+    pub fn size(&mut self, size: u32) -> &mut Self {
+        self.0.size = Some(size);
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> SplitOpts {
+        std::mem::take(&mut self.0)
+    }
+}
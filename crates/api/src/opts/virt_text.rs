@@ -0,0 +1,45 @@
+use crate::types::{ExtmarkHlMode, ExtmarkVirtTextPosition};
+
+/// Options passed to
+/// [`Buffer::set_virtual_text()`](crate::Buffer::set_virtual_text).
+#[derive(Clone, Debug, Default)]
+pub struct VirtTextOpts {
+    pub(crate) hl_mode: Option<ExtmarkHlMode>,
+    pub(crate) virt_text_pos: Option<ExtmarkVirtTextPosition>,
+}
+
+impl VirtTextOpts {
+    #[inline(always)]
+    pub fn builder() -> VirtTextOptsBuilder {
+        VirtTextOptsBuilder::default()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct VirtTextOptsBuilder(VirtTextOpts);
+
+impl VirtTextOptsBuilder {
+    /// How the highlights of the virtual text are combined with the ones of
+    /// the text below it. Defaults to [`ExtmarkHlMode::Replace`].
+    #[inline]
+    pub fn hl_mode(&mut self, hl_mode: ExtmarkHlMode) -> &mut Self {
+        self.0.hl_mode = Some(hl_mode);
+        self
+    }
+
+    /// Where to show the virtual text. Defaults to
+    /// [`ExtmarkVirtTextPosition::Eol`].
+    #[inline]
+    pub fn virt_text_pos(
+        &mut self,
+        virt_text_pos: ExtmarkVirtTextPosition,
+    ) -> &mut Self {
+        self.0.virt_text_pos = Some(virt_text_pos);
+        self
+    }
+
+    #[inline]
+    pub fn build(&mut self) -> VirtTextOpts {
+        std::mem::take(&mut self.0)
+    }
+}
@@ -0,0 +1,78 @@
+use ropey::{Rope, RopeBuilder};
+use types as nvim;
+
+use crate::Buffer;
+use crate::Result;
+
+/// The maximum number of lines passed to a single call to
+/// `nvim_buf_set_lines()` by [`Buffer::set_from_rope()`].
+const LINES_PER_BATCH: usize = 4096;
+
+impl Buffer {
+    /// Returns the contents of the buffer as a [`Rope`], with the lines
+    /// joined by `\n`.
+    ///
+    /// There's no trailing newline after the last line, so an empty buffer
+    /// becomes an empty rope. Bytes that aren't valid UTF-8 are replaced with
+    /// `U+FFFD`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ropey")))]
+    pub fn get_as_rope(&self) -> Result<Rope> {
+        let mut builder = RopeBuilder::new();
+        for (idx, line) in self.get_lines(.., true)?.enumerate() {
+            if idx > 0 {
+                builder.append("\n");
+            }
+            builder.append(&line.to_string_lossy());
+        }
+        Ok(builder.finish())
+    }
+
+    /// Replaces the contents of the buffer with the text of `rope`, split
+    /// into lines at every `\n`.
+    ///
+    /// This is the inverse of [`get_as_rope()`](Self::get_as_rope): a
+    /// trailing `\n` adds an empty last line, and `\r`s are kept as part of
+    /// the lines. The lines are set in batches of a few thousand, so the
+    /// whole text is never converted at once.
+    #[cfg_attr(docsrs, doc(cfg(feature = "ropey")))]
+    pub fn set_from_rope(&mut self, rope: &Rope) -> Result<()> {
+        let mut batch = Vec::with_capacity(LINES_PER_BATCH);
+        let mut line = String::new();
+        let mut lines_set = 0;
+
+        for chunk in rope.chunks() {
+            let mut pieces = chunk.split('\n');
+            line.push_str(pieces.next().expect("split yields at least once"));
+
+            for piece in pieces {
+                batch.push(nvim::String::from(line.as_str()));
+                line.clear();
+                line.push_str(piece);
+
+                if batch.len() == LINES_PER_BATCH {
+                    self.set_batch(&mut lines_set, &mut batch)?;
+                }
+            }
+        }
+
+        batch.push(nvim::String::from(line.as_str()));
+        self.set_batch(&mut lines_set, &mut batch)
+    }
+
+    /// Sets the lines in `batch` after the first `lines_set` lines, replacing
+    /// the rest of the buffer if it's the first batch.
+    fn set_batch(
+        &mut self,
+        lines_set: &mut usize,
+        batch: &mut Vec<nvim::String>,
+    ) -> Result<()> {
+        let len = batch.len();
+        if *lines_set == 0 {
+            self.set_lines(.., true, batch.drain(..))?;
+        } else {
+            self.set_lines(*lines_set..*lines_set, true, batch.drain(..))?;
+        }
+        *lines_set += len;
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+//! Functions to talk to the clients connected to Neovim via [msgpack-RPC][1].
+//!
+//! The arguments are serialized by Neovim and sent over the channel with the
+//! standard msgpack-RPC framing, so the other end sees exactly the same
+//! messages it would receive from `vim.rpcnotify()` and `vim.rpcrequest()`.
+//!
+//! Like the rest of the API, these functions can only be called from
+//! Neovim's main thread. Code running on another thread, e.g. on an async
+//! runtime, needs to hand the message over to the main thread first, for
+//! example with [`nvim_oxi::schedule()`][2].
+//!
+//! [1]: https://neovim.io/doc/user/api.html#RPC
+//! [2]: https://docs.rs/nvim-oxi/latest/nvim_oxi/fn.schedule.html
+
+use types::{Array, Object, conversion::FromObject};
+
+use crate::Result;
+use crate::call_function;
+
+/// Wrapper around [`rpcnotify()`][1].
+///
+/// Sends a notification to the client connected to `channel_id`, without
+/// waiting for it to be handled. The elements of `args` are sent as the
+/// notification's parameters.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#rpcnotify()
+pub fn send<Args>(channel_id: u32, method: &str, args: Args) -> Result<()>
+where
+    Args: Into<Array>,
+{
+    call_function::<_, Object>("rpcnotify", rpc_args(channel_id, method, args))
+        .map(|_| ())
+}
+
+/// Wrapper around [`rpcrequest()`][1].
+///
+/// Sends a request to the client connected to `channel_id` and blocks until
+/// it responds, returning the response. The elements of `args` are sent as
+/// the request's parameters. If the client responds with an error it's
+/// returned as an [`Error::Nvim`](crate::Error::Nvim).
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#rpcrequest()
+pub fn request<Args, Ret>(
+    channel_id: u32,
+    method: &str,
+    args: Args,
+) -> Result<Ret>
+where
+    Args: Into<Array>,
+    Ret: FromObject,
+{
+    call_function("rpcrequest", rpc_args(channel_id, method, args))
+}
+
+/// Prepends the channel and the method to the arguments, which is how both
+/// `rpcnotify()` and `rpcrequest()` expect them.
+fn rpc_args(channel_id: u32, method: &str, args: impl Into<Array>) -> Array {
+    let mut rpc_args = Array::from((channel_id, method));
+    rpc_args.extend(args.into());
+    rpc_args
+}
@@ -0,0 +1,115 @@
+//! Functions to work with Neovim's spell checker (see `:h spell`).
+//!
+//! Most of these only give meaningful results when the `'spell'` option is
+//! set and `'spelllang'` lists at least one language whose spell file is
+//! installed.
+
+use crate::Result;
+use crate::call_function;
+use crate::opts::CmdOpts;
+use crate::types::CmdInfos;
+
+/// The kind of misspelled word returned by [`bad_word()`].
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SpellBadType {
+    /// A word that isn't in the dictionary.
+    Bad,
+
+    /// A word that is only valid in another region of the language.
+    Local,
+
+    /// A rare word.
+    Rare,
+
+    /// A word that should start with a capital letter.
+    Cap,
+}
+
+/// How [`add_word()`] marks a word.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SpellAddType {
+    /// Mark the word as correctly spelled, like `:spellgood`.
+    Good,
+
+    /// Mark the word as misspelled, like `:spellwrong`.
+    Wrong,
+
+    /// Mark the word as rare, like `:spellrare`.
+    Rare,
+
+    /// Remove a word previously added with one of the other variants, like
+    /// `:spellundo`.
+    Undo,
+}
+
+impl SpellBadType {
+    #[inline]
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "bad" => Some(Self::Bad),
+            "local" => Some(Self::Local),
+            "rare" => Some(Self::Rare),
+            "caps" => Some(Self::Cap),
+            _ => None,
+        }
+    }
+}
+
+impl SpellAddType {
+    #[inline]
+    fn command(self) -> &'static str {
+        match self {
+            Self::Good => "spellgood",
+            Self::Wrong => "spellwrong",
+            Self::Rare => "spellrare",
+            Self::Undo => "spellundo",
+        }
+    }
+}
+
+/// Wrapper around [`spellbadword()`][1].
+///
+/// Returns the misspelled word under or after the cursor, together with the
+/// kind of mistake, or `None` if there's no misspelled word.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#spellbadword()
+pub fn bad_word() -> Result<Option<(String, SpellBadType)>> {
+    let res =
+        call_function::<_, Vec<String>>("spellbadword", types::Array::new())?;
+    let [word, kind] = <[String; 2]>::try_from(res).map_err(|res| {
+        crate::Error::custom(format!("unexpected spellbadword(): {res:?}"))
+    })?;
+    Ok(SpellBadType::from_str(&kind)
+        .filter(|_| !word.is_empty())
+        .map(|kind| (word, kind)))
+}
+
+/// Wrapper around [`spellsuggest()`][1].
+///
+/// Returns at most `max` suggestions to replace `word`, with the best ones
+/// first.
+///
+/// [1]: https://neovim.io/doc/user/builtin.html#spellsuggest()
+pub fn suggest(word: &str, max: u32) -> Result<Vec<String>> {
+    call_function("spellsuggest", (word, max))
+}
+
+/// Adds `word` to the spell checker's word lists, or removes it if `kind`
+/// is [`SpellAddType::Undo`].
+///
+/// If `persistent` is `true` the word is written to `'spellfile'`, like
+/// `zg` does, so it's remembered across sessions. Otherwise it's only added
+/// to the internal word list, like `zG`, which is lost when Neovim exits.
+pub fn add_word(
+    word: &str,
+    kind: SpellAddType,
+    persistent: bool,
+) -> Result<()> {
+    let infos = CmdInfos::builder()
+        .cmd(kind.command())
+        .bang(!persistent)
+        .args([word])
+        .build();
+    crate::cmd(&infos, &CmdOpts::default()).map(|_| ())
+}
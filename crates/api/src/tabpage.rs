@@ -151,6 +151,9 @@ impl TabPage {
     ///
     /// Checks if a tabpage is valid.
     ///
+    /// Like for [`Window::is_valid`](crate::Window::is_valid), a tabpage stays
+    /// invalid once it's been closed, since its handle is never reused.
+    ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_tabpage_is_valid()
     pub fn is_valid(&self) -> bool {
         unsafe { nvim_tabpage_is_valid(self.0) }
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use types::{
+    Object,
+    conversion::{self, FromObject},
+    serde::Deserializer,
+};
+
+/// The version of the running Neovim instance and of its API, returned by
+/// [`api_version()`](crate::api_version).
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct ApiVersion {
+    /// Major version of Neovim, e.g. 0 for 0.10.4.
+    pub major: u32,
+
+    /// Minor version of Neovim, e.g. 10 for 0.10.4.
+    pub minor: u32,
+
+    /// Patch version of Neovim, e.g. 4 for 0.10.4.
+    pub patch: u32,
+
+    /// Whether this is a development version, e.g. a nightly build.
+    pub prerelease: bool,
+
+    /// The current level of the API, which is incremented every time a
+    /// function is added to it. See `:h api-level`.
+    pub api_level: u32,
+
+    /// The oldest API level this instance is backwards-compatible with.
+    pub api_compatible: u32,
+
+    /// Whether the functions added in the current API level may still
+    /// change.
+    pub api_prerelease: bool,
+}
+
+impl FromObject for ApiVersion {
+    fn from_object(obj: Object) -> Result<Self, conversion::Error> {
+        Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
+    }
+}
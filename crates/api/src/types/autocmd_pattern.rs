@@ -0,0 +1,64 @@
+use types::Object;
+
+use crate::Buffer;
+
+/// A pattern which an autocommand is registered for, passed to
+/// [`CreateAutocmdOpts`](crate::opts::CreateAutocmdOpts).
+///
+/// See `:h autocmd-pattern` and `:h autocmd-buflocal` for details.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AutocmdPattern {
+    /// A file pattern like `*.rs`, or the string matched by events like
+    /// `User` or `FileType`.
+    Glob(String),
+
+    /// The buffer that's current when the autocommand is created, like
+    /// `<buffer>`.
+    Buffer,
+
+    /// A specific buffer, like `<buffer=N>`.
+    SpecificBuffer(Buffer),
+}
+
+impl From<&str> for AutocmdPattern {
+    /// Parses `<buffer>` and `<buffer=N>` as buffer-local patterns, and
+    /// everything else as a glob.
+    fn from(pattern: &str) -> Self {
+        if pattern == "<buffer>" {
+            return Self::Buffer;
+        }
+
+        pattern
+            .strip_prefix("<buffer=")
+            .and_then(|rest| rest.strip_suffix('>'))
+            .and_then(|bufnr| bufnr.parse::<i32>().ok())
+            .map(|bufnr| Self::SpecificBuffer(bufnr.into()))
+            .unwrap_or_else(|| Self::Glob(pattern.to_owned()))
+    }
+}
+
+impl From<String> for AutocmdPattern {
+    #[inline]
+    fn from(pattern: String) -> Self {
+        pattern.as_str().into()
+    }
+}
+
+impl From<Buffer> for AutocmdPattern {
+    #[inline]
+    fn from(buffer: Buffer) -> Self {
+        Self::SpecificBuffer(buffer)
+    }
+}
+
+impl From<AutocmdPattern> for Object {
+    fn from(pattern: AutocmdPattern) -> Self {
+        match pattern {
+            AutocmdPattern::Glob(glob) => glob.into(),
+            AutocmdPattern::Buffer => "<buffer>".into(),
+            AutocmdPattern::SpecificBuffer(buffer) => {
+                format!("<buffer={}>", buffer.handle()).into()
+            },
+        }
+    }
+}
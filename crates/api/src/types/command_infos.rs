@@ -14,7 +14,8 @@ use super::{CommandAddr, CommandArgs, CommandNArgs, CommandRange};
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize)]
 pub struct CommandInfos {
-    /// TODO: docs
+    /// The domain of the command's range, if it was defined with
+    /// `-addr`.
     pub addr: Option<CommandAddr>,
 
     /// Whether the command can take a `!` modifier.
@@ -26,17 +27,19 @@ pub struct CommandInfos {
     /// Callback triggered by the command.
     pub callback: Option<Function<CommandArgs, ()>>,
 
-    /// Command complletion strategy.
+    /// Command completion strategy.
     pub complete: Option<String>,
 
-    /// TODO: docs
+    /// The name of the function used to complete the command's arguments,
+    /// for `custom` and `customlist` completions.
     pub complete_arg: Option<String>,
 
-    /// TODO: docs
+    /// The default count of the command, if it was defined with `-count`.
     #[serde(deserialize_with = "parse_count")]
     pub count: Option<u32>,
 
-    /// TODO: docs
+    /// The replacement text of the command, or its `desc` if it was
+    /// defined with a Lua callback.
     pub definition: Option<String>,
 
     /// Whether to use the invocation location as opposed to the definition
@@ -50,14 +53,15 @@ pub struct CommandInfos {
     #[serde(default)]
     pub nargs: CommandNArgs,
 
-    /// TODO: docs
+    /// The range accepted by the command, if it was defined with `-range`.
     pub range: Option<CommandRange>,
 
-    /// Whether the firrst argument to the command can be an optional register
+    /// Whether the first argument to the command can be an optional register
     /// name (like `:del`, `:put` or `:yank`).
     pub register: bool,
 
-    /// TODO: docs
+    /// The script ID of the script in which the command was defined, or a
+    /// negative value if it wasn't defined in a script.
     pub script_id: i32,
 }
 
@@ -0,0 +1,30 @@
+use luajit::{self as lua, Pushable};
+use types::Integer;
+
+/// What a command's [`preview`](crate::opts::CreateCommandOptsBuilder::preview)
+/// callback returns to tell Neovim how to show the preview.
+///
+/// Previews are only shown if the `'inccommand'` option is set to `"nosplit"`
+/// or `"split"`. See `:h :command-preview` for more infos.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CommandPreview {
+    /// Don't show a preview.
+    #[default]
+    NoPreview = 0,
+
+    /// Show the preview in the current buffer, without opening the preview
+    /// window.
+    Replace = 1,
+
+    /// Show the preview in the current buffer and, if `'inccommand'` is set
+    /// to `"split"`, also open the preview window showing the preview buffer.
+    Split = 2,
+}
+
+impl Pushable for CommandPreview {
+    #[inline]
+    unsafe fn push(self, lstate: *mut lua::ffi::State) -> std::ffi::c_int {
+        unsafe { (self as Integer).push(lstate) }
+    }
+}
@@ -0,0 +1,71 @@
+/// The position of the cursor in a window, returned by
+/// [`Window::get_cursor_position()`](crate::Window::get_cursor_position).
+///
+/// Neovim reports the cursor row as 1-indexed and the column as a 0-indexed
+/// byte offset. This provides both 0- and 1-indexed rows, and the column as
+/// both a byte and a character offset, so that callers don't have to convert
+/// them by hand.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CursorPosition {
+    row: usize,
+    col_byte: usize,
+    col_char: usize,
+}
+
+impl CursorPosition {
+    /// Creates a new position from Neovim's 1-indexed `row` and 0-indexed
+    /// byte column `col`, using `line` (the text of the row) to compute the
+    /// character column.
+    #[inline]
+    pub(crate) fn new(row: usize, col: usize, line: &[u8]) -> Self {
+        let col_char = String::from_utf8_lossy(&line[..col.min(line.len())])
+            .chars()
+            .count();
+        Self { row, col_byte: col, col_char }
+    }
+
+    /// The 0-indexed row of the cursor.
+    #[inline]
+    pub fn row_0(&self) -> usize {
+        self.row - 1
+    }
+
+    /// The 1-indexed row of the cursor, as used by Neovim.
+    #[inline]
+    pub fn row_1(&self) -> usize {
+        self.row
+    }
+
+    /// The 0-indexed byte offset of the cursor in its row, as used by Neovim.
+    #[inline]
+    pub fn col_byte(&self) -> usize {
+        self.col_byte
+    }
+
+    /// The number of characters before the cursor in its row. Invalid UTF-8
+    /// sequences are counted as one character each.
+    #[inline]
+    pub fn col_char(&self) -> usize {
+        self.col_char
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_position_multibyte() {
+        let pos = CursorPosition::new(3, 4, "héllo".as_bytes());
+        assert_eq!(pos.row_0(), 2);
+        assert_eq!(pos.row_1(), 3);
+        assert_eq!(pos.col_byte(), 4);
+        assert_eq!(pos.col_char(), 3);
+    }
+
+    #[test]
+    fn cursor_position_past_end() {
+        let pos = CursorPosition::new(1, 10, b"abc");
+        assert_eq!(pos.col_char(), 3);
+    }
+}
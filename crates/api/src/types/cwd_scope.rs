@@ -0,0 +1,19 @@
+use crate::{TabPage, Window};
+
+/// The scope of the working directory returned by
+/// [`getcwd()`](crate::getcwd).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CwdScope {
+    /// The global working directory, ignoring any directory set via `:lcd` or
+    /// `:tcd`.
+    #[default]
+    Global,
+
+    /// The working directory of a window, which is the one set via `:lcd`,
+    /// falling back to the tabpage-local and then to the global one.
+    Window(Window),
+
+    /// The working directory of a tabpage, which is the one set via `:tcd`,
+    /// falling back to the global one.
+    Tab(TabPage),
+}
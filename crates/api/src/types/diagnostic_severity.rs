@@ -0,0 +1,19 @@
+use types::{Integer, Object};
+
+/// The severity of a diagnostic, matching the values in
+/// `vim.diagnostic.severity`.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Hint = 4,
+}
+
+impl From<DiagnosticSeverity> for Object {
+    #[inline]
+    fn from(severity: DiagnosticSeverity) -> Self {
+        (severity as Integer).into()
+    }
+}
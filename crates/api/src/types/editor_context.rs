@@ -7,24 +7,38 @@ use types::{
     serde::Deserializer,
 };
 
+/// A snapshot of the editor state returned by
+/// [`get_context()`](crate::get_context), which can later be restored with
+/// [`load_context()`](crate::load_context).
+///
+/// Every field holds the serialized state of one of the [`ContextType`]s, and
+/// is empty if that type wasn't requested when getting the context.
+///
+/// [`ContextType`]: crate::types::ContextType
 #[non_exhaustive]
 #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
 pub struct EditorContext {
+    /// The buffer list.
     #[serde(default, rename = "bufs")]
     pub bufferlist: Vec<types::String>,
 
+    /// The global (`g:`) variables.
     #[serde(default, rename = "gvars")]
     pub global_vars: Vec<types::String>,
 
+    /// The global and script-local functions.
     #[serde(default, rename = "funcs")]
     pub global_and_script_local_funcs: Vec<types::String>,
 
+    /// The jumplist.
     #[serde(default, rename = "jumps")]
     pub jumplist: Vec<types::String>,
 
+    /// The registers.
     #[serde(default, rename = "regs")]
     pub registers: Vec<types::String>,
 
+    /// The script-local functions.
     #[serde(default, rename = "sfuncs")]
     pub script_local_funcs: Vec<types::String>,
 }
@@ -116,16 +130,15 @@ impl EditorContextBuilder {
 
 impl From<EditorContext> for Dictionary {
     fn from(ctx: EditorContext) -> Self {
+        // The keys have to match the ones used by `nvim_get_context()`, or
+        // `nvim_load_context()` will silently ignore them.
         Self::from_iter([
-            ("bufferlist", Array::from_iter(ctx.bufferlist)),
-            ("global_vars", Array::from_iter(ctx.global_vars)),
-            (
-                "global_and_script_local_funcs",
-                Array::from_iter(ctx.global_and_script_local_funcs),
-            ),
-            ("jumplist", Array::from_iter(ctx.jumplist)),
-            ("registers", Array::from_iter(ctx.registers)),
-            ("script_local_funcs", Array::from_iter(ctx.script_local_funcs)),
+            ("bufs", Array::from_iter(ctx.bufferlist)),
+            ("gvars", Array::from_iter(ctx.global_vars)),
+            ("funcs", Array::from_iter(ctx.global_and_script_local_funcs)),
+            ("jumps", Array::from_iter(ctx.jumplist)),
+            ("regs", Array::from_iter(ctx.registers)),
+            ("sfuncs", Array::from_iter(ctx.script_local_funcs)),
         ])
     }
 }
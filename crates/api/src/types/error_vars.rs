@@ -0,0 +1,18 @@
+/// The `v:` variables describing the last error, returned by
+/// [`error_vars()`](crate::error_vars).
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ErrorVars {
+    /// The last error message that was shown (`v:errmsg`). Unlike the other
+    /// fields this is kept after the error is handled, and is also set by
+    /// errors silenced with `:silent!`.
+    pub errmsg: String,
+
+    /// The exception that was most recently caught and not finished
+    /// (`v:exception`), or an empty string outside of a `:catch` block.
+    pub exception: String,
+
+    /// The point where the exception in [`exception`](Self::exception) was
+    /// thrown (`v:throwpoint`).
+    pub throwpoint: String,
+}
@@ -0,0 +1,116 @@
+use core::fmt;
+
+use luajit::{self as lua, Poppable};
+use serde::de::{self, Deserialize};
+use types::{
+    Object,
+    conversion::{self, FromObject},
+    serde::Deserializer,
+};
+
+use super::ExtmarkInfos;
+
+/// An extmark returned by
+/// [`Buffer::get_extmarks()`](crate::Buffer::get_extmarks).
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Extmark {
+    /// The id of the extmark in its namespace.
+    pub id: u32,
+
+    /// The 0-indexed line the extmark starts at.
+    pub row: usize,
+
+    /// The 0-indexed byte column the extmark starts at.
+    pub col: usize,
+
+    /// The details of the extmark, only present if they were requested via
+    /// the `details` option.
+    pub details: Option<ExtmarkInfos>,
+}
+
+impl<'de> Deserialize<'de> for Extmark {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct ExtmarkVisitor;
+
+        impl<'de> de::Visitor<'de> for ExtmarkVisitor {
+            type Value = Extmark;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an [id, row, col, details?] array")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let id = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+
+                let row = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+                let col = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+
+                let details = seq.next_element()?;
+
+                Ok(Extmark { id, row, col, details })
+            }
+        }
+
+        deserializer.deserialize_seq(ExtmarkVisitor)
+    }
+}
+
+impl FromObject for Extmark {
+    fn from_object(obj: Object) -> Result<Self, conversion::Error> {
+        Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
+    }
+}
+
+impl Poppable for Extmark {
+    unsafe fn pop(lstate: *mut lua::ffi::State) -> Result<Self, lua::Error> {
+        let obj = unsafe { Object::pop(lstate) }?;
+
+        Self::from_object(obj)
+            .map_err(lua::Error::pop_error_from_err::<Self, _>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use types::{Array, Dictionary};
+
+    use super::*;
+
+    #[test]
+    fn extmark_without_details() {
+        let obj = Object::from(Array::from((1, 2, 3)));
+        let extmark = Extmark::from_object(obj).unwrap();
+        assert_eq!((extmark.id, extmark.row, extmark.col), (1, 2, 3));
+        assert_eq!(extmark.details, None);
+    }
+
+    #[test]
+    fn extmark_with_missing_details_fields() {
+        let details = Dictionary::from_iter([("right_gravity", true)]);
+        let obj = Object::from(Array::from((1, 0, 0, details)));
+        let details = Extmark::from_object(obj).unwrap().details.unwrap();
+        assert!(details.right_gravity);
+        assert_eq!(details.sign_text, None);
+        assert!(details.virt_text.is_empty());
+    }
+
+    #[test]
+    fn extmark_missing_col() {
+        let obj = Object::from(Array::from((1, 2)));
+        assert!(Extmark::from_object(obj).is_err());
+    }
+}
@@ -7,10 +7,18 @@ use types::{
 
 use super::{ExtmarkHlMode, ExtmarkVirtTextChunk, ExtmarkVirtTextPosition};
 
-/// Extmark infos returned by `Buffer::get_extmark_by_id`.
+/// Extmark infos returned by `Buffer::get_extmark_by_id` and in the
+/// [`details`](super::Extmark::details) of the extmarks returned by
+/// `Buffer::get_extmarks`.
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize)]
 pub struct ExtmarkInfos {
+    #[serde(default)]
+    pub conceal: Option<String>,
+
+    #[serde(default)]
+    pub cursorline_hl_group: Option<String>,
+
     #[serde(default)]
     pub end_col: Option<usize>,
 
@@ -36,14 +44,32 @@ pub struct ExtmarkInfos {
     #[serde(default)]
     pub hl_mode: Option<ExtmarkHlMode>,
 
+    #[serde(default)]
+    pub line_hl_group: Option<String>,
+
+    #[serde(default)]
+    pub number_hl_group: Option<String>,
+
     #[serde(default)]
     pub priority: Option<u32>,
 
     pub right_gravity: bool,
 
+    #[serde(default)]
+    pub sign_hl_group: Option<String>,
+
+    #[serde(default)]
+    pub sign_text: Option<String>,
+
+    #[serde(default)]
+    pub spell: Option<bool>,
+
     #[serde(default)]
     pub ui_watched: Option<bool>,
 
+    #[serde(default)]
+    pub url: Option<String>,
+
     #[serde(default)]
     pub virt_lines: Option<Vec<Vec<(String, String)>>>,
 
@@ -62,6 +88,9 @@ pub struct ExtmarkInfos {
     #[serde(default)]
     pub virt_text_pos: Option<ExtmarkVirtTextPosition>,
 
+    #[serde(default)]
+    pub virt_text_repeat_linebreak: Option<bool>,
+
     #[serde(default)]
     pub virt_text_win_col: Option<i64>,
 }
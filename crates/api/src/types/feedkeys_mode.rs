@@ -0,0 +1,140 @@
+use core::ops::{BitOr, BitOrAssign};
+
+use luajit::BitFlags;
+use luajit::ffi::Integer;
+
+/// The mode flags passed to [`feedkeys()`](crate::feedkeys).
+///
+/// Flags can be combined with `|`, and converted into the string expected by
+/// Neovim via [`types::String::from`]:
+///
+/// ```ignore
+/// let mode = FeedKeysMode::NOREMAP | FeedKeysMode::EXECUTE;
+/// api::feedkeys("ihello<Esc>", &types::String::from(mode), false);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FeedKeysMode(u8);
+
+impl FeedKeysMode {
+    /// Remap keys (`m`). This is the default.
+    pub const REMAP: Self = Self(1 << 0);
+
+    /// Do not remap keys (`n`).
+    pub const NOREMAP: Self = Self(1 << 1);
+
+    /// Handle keys as if typed (`t`).
+    pub const TYPED: Self = Self(1 << 2);
+
+    /// Insert the keys instead of appending them (`i`).
+    pub const INSERT: Self = Self(1 << 3);
+
+    /// Execute commands until the typeahead is empty (`x`).
+    pub const EXECUTE: Self = Self(1 << 4);
+
+    /// When used with [`EXECUTE`](Self::EXECUTE), do not end Insert mode
+    /// (`!`).
+    pub const NO_END_INSERT: Self = Self(1 << 5);
+
+    /// Do not use the typeahead buffer, feeding the keys as low-level input
+    /// (`L`).
+    pub const LOW_LEVEL: Self = Self(1 << 6);
+
+    const FLAGS: [(Self, char); 7] = [
+        (Self::REMAP, 'm'),
+        (Self::NOREMAP, 'n'),
+        (Self::TYPED, 't'),
+        (Self::INSERT, 'i'),
+        (Self::EXECUTE, 'x'),
+        (Self::NO_END_INSERT, '!'),
+        (Self::LOW_LEVEL, 'L'),
+    ];
+
+    const ALL_BITS: u8 = (1 << Self::FLAGS.len()) - 1;
+
+    /// Returns the empty set of flags.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns `true` if all the flags in `other` are also set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if no flags are set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for FeedKeysMode {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FeedKeysMode {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitFlags for FeedKeysMode {
+    #[inline]
+    fn bits(self) -> Integer {
+        self.0 as Integer
+    }
+
+    #[inline]
+    fn from_bits(bits: Integer) -> Option<Self> {
+        u8::try_from(bits)
+            .ok()
+            .filter(|bits| bits & !Self::ALL_BITS == 0)
+            .map(Self)
+    }
+}
+
+impl From<FeedKeysMode> for types::String {
+    fn from(mode: FeedKeysMode) -> Self {
+        FeedKeysMode::FLAGS
+            .iter()
+            .filter(|(flag, _)| mode.contains(*flag))
+            .map(|&(_, ch)| ch)
+            .collect::<std::string::String>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_string() {
+        let mode = FeedKeysMode::NOREMAP | FeedKeysMode::EXECUTE;
+        assert_eq!(types::String::from("nx"), types::String::from(mode));
+        assert_eq!(
+            types::String::from(""),
+            types::String::from(FeedKeysMode::empty())
+        );
+    }
+
+    #[test]
+    fn bits_roundtrip() {
+        let mode = FeedKeysMode::TYPED | FeedKeysMode::LOW_LEVEL;
+        assert_eq!(Some(mode), FeedKeysMode::from_bits(mode.bits()));
+    }
+
+    #[test]
+    fn from_unknown_bits() {
+        assert_eq!(None, FeedKeysMode::from_bits(1 << 7));
+        assert_eq!(None, FeedKeysMode::from_bits(-1));
+    }
+}
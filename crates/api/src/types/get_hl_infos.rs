@@ -4,6 +4,7 @@ use super::HighlightInfos;
 use crate::SuperIterator;
 
 /// The type returned by [`get_hl`](crate::get_hl).
+#[allow(clippy::large_enum_variant)]
 pub enum GetHlInfos<Map: SuperIterator<(NvimString, HighlightInfos)>> {
     /// The [`HighlightInfos`] relative to a specific highlight group.
     ///
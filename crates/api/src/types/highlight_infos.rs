@@ -5,6 +5,8 @@ use types::{
     serde::Deserializer,
 };
 
+use crate::color::Color;
+
 /// Attributes related to a highlight group.
 #[non_exhaustive]
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Default)]
@@ -12,22 +14,30 @@ pub struct HighlightInfos {
     pub altfont: Option<bool>,
     #[cfg_attr(not(feature = "neovim-0-11"), serde(alias = "bg"))]
     #[cfg_attr(feature = "neovim-0-11", serde(rename = "bg"))]
-    pub background: Option<u32>,
+    pub background: Option<Color>,
     pub bg_indexed: Option<bool>,
     pub blend: Option<u32>,
     pub bold: Option<bool>,
+    /// The background color used in terminals without true colors.
+    #[serde(default, deserialize_with = "crate::color::deserialize_terminal")]
+    pub ctermbg: Option<Color>,
+    /// The foreground color used in terminals without true colors.
+    #[serde(default, deserialize_with = "crate::color::deserialize_terminal")]
+    pub ctermfg: Option<Color>,
     pub default: Option<bool>,
     pub fallback: Option<bool>,
     pub fg_indexed: Option<bool>,
     pub force: Option<bool>,
     #[cfg_attr(not(feature = "neovim-0-11"), serde(alias = "fg"))]
     #[cfg_attr(feature = "neovim-0-11", serde(rename = "fg"))]
-    pub foreground: Option<u32>,
+    pub foreground: Option<Color>,
     pub italic: Option<bool>,
+    /// The name of the group this one is linked to, if any.
+    pub link: Option<String>,
     pub reverse: Option<bool>,
     #[cfg_attr(not(feature = "neovim-0-11"), serde(alias = "sp"))]
     #[cfg_attr(feature = "neovim-0-11", serde(rename = "sp"))]
-    pub special: Option<u32>,
+    pub special: Option<Color>,
     pub standout: Option<bool>,
     pub strikethrough: Option<bool>,
     pub undercurl: Option<bool>,
@@ -37,6 +47,25 @@ pub struct HighlightInfos {
     pub underlineline: Option<bool>,
 }
 
+impl HighlightInfos {
+    /// Decodes the `foreground`, `background` and `special` colors as
+    /// [`Terminal`](Color::Terminal) colors, for the definitions returned by
+    /// `nvim_get_hl_by_{id,name}()` when `rgb` is false.
+    pub(crate) fn rgb_to_terminal(mut self) -> crate::Result<Self> {
+        for color in
+            [&mut self.foreground, &mut self.background, &mut self.special]
+        {
+            if let Some(c) = color.take() {
+                let terminal = c.rgb_to_terminal().ok_or_else(|| {
+                    crate::Error::custom("terminal colors range from 0 to 255")
+                })?;
+                *color = Some(terminal);
+            }
+        }
+        Ok(self)
+    }
+}
+
 impl FromObject for HighlightInfos {
     fn from_object(obj: Object) -> Result<Self, conversion::Error> {
         Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
@@ -18,7 +18,10 @@ pub struct KeymapInfos {
     #[serde(deserialize_with = "utils::zero_is_none")]
     pub buffer: Option<Buffer>,
 
-    /// Optional callback triggered by the keymap.
+    /// The Lua function triggered by the keymap, if it was defined with a
+    /// callback instead of a string right-hand side. The returned
+    /// [`Function`] holds its own reference in the Lua registry, so it can be
+    /// called even after the keymap has been deleted.
     pub callback: Option<Function<(), ()>>,
 
     /// Whether the keymap argument is an expression.
@@ -8,3 +8,29 @@ pub enum LogLevel {
     Error = 4,
     Off = 5,
 }
+
+impl LogLevel {
+    /// Returns the highlight group used to show a message with this level,
+    /// or `None` if it's shown with the default highlighting.
+    pub(crate) fn message_hl_group(self) -> Option<&'static str> {
+        match self {
+            Self::Error => Some("ErrorMsg"),
+            Self::Warn => Some("WarningMsg"),
+            Self::Info => Some("ModeMsg"),
+            Self::Trace | Self::Debug | Self::Off => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_hl_group() {
+        assert_eq!(LogLevel::Error.message_hl_group(), Some("ErrorMsg"));
+        assert_eq!(LogLevel::Warn.message_hl_group(), Some("WarningMsg"));
+        assert_eq!(LogLevel::Info.message_hl_group(), Some("ModeMsg"));
+        assert_eq!(LogLevel::Debug.message_hl_group(), None);
+    }
+}
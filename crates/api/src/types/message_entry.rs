@@ -0,0 +1,8 @@
+/// An entry in the message history, as returned by
+/// [`get_messages()`](crate::get_messages).
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MessageEntry {
+    /// The text of the message.
+    pub content: String,
+}
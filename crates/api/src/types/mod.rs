@@ -1,7 +1,9 @@
 //! Contains various types given to and returned from Neovim API functions.
 
+mod api_version;
 mod autocmd_callback_args;
 mod autocmd_infos;
+mod autocmd_pattern;
 mod channel_infos;
 mod client_infos;
 mod cmd_infos;
@@ -13,22 +15,30 @@ mod command_complete;
 mod command_infos;
 mod command_modifiers;
 mod command_nargs;
+mod command_preview;
 mod command_range;
 mod context_type;
+mod cursor_position;
+mod cwd_scope;
+mod diagnostic_severity;
 #[cfg(feature = "neovim-nightly")] // On Nightly.
 mod echo_message_id;
 mod editor_context;
+mod error_vars;
+mod extmark;
 mod extmark_hl_mode;
 mod extmark_infos;
 mod extmark_position;
 mod extmark_virt_text_chunk;
 mod extmark_virt_text_position;
+mod feedkeys_mode;
 mod get_extmarks_namespace_id;
 mod get_hl_infos;
 mod got_mode;
 mod highlight_infos;
 mod keymap_infos;
 mod log_level;
+mod message_entry;
 mod mode;
 mod mouse_action;
 mod mouse_button;
@@ -37,13 +47,17 @@ mod option_infos;
 mod parsed_viml_expression;
 mod paste_phase;
 mod proc_infos;
+mod process_info;
 #[cfg(feature = "neovim-nightly")] // On Nightly.
 mod progress_message_status;
 mod register_type;
+mod sign_definition;
+mod split_command;
 mod split_direction;
 mod split_modifier;
 mod statusline_highlight_infos;
 mod statusline_infos;
+mod syn_attr;
 mod ui_infos;
 mod viml_ast_node;
 mod virt_lines_overflow;
@@ -57,8 +71,10 @@ mod window_style;
 mod window_title;
 mod window_title_position;
 
+pub use api_version::*;
 pub use autocmd_callback_args::*;
 pub use autocmd_infos::*;
+pub use autocmd_pattern::*;
 pub use channel_infos::*;
 pub use client_infos::*;
 pub use cmd_infos::*;
@@ -70,22 +86,30 @@ pub use command_complete::*;
 pub use command_infos::*;
 pub use command_modifiers::*;
 pub use command_nargs::*;
+pub use command_preview::*;
 pub use command_range::*;
 pub use context_type::*;
+pub use cursor_position::*;
+pub use cwd_scope::*;
+pub use diagnostic_severity::*;
 #[cfg(feature = "neovim-nightly")] // On Nightly.
 pub use echo_message_id::EchoMessageId;
 pub use editor_context::*;
+pub use error_vars::*;
+pub use extmark::*;
 pub use extmark_hl_mode::*;
 pub use extmark_infos::*;
 pub use extmark_position::*;
 pub use extmark_virt_text_chunk::*;
 pub use extmark_virt_text_position::*;
+pub use feedkeys_mode::*;
 pub use get_extmarks_namespace_id::GetExtmarksNamespaceId;
 pub use get_hl_infos::GetHlInfos;
 pub use got_mode::*;
 pub use highlight_infos::*;
 pub use keymap_infos::*;
 pub use log_level::*;
+pub use message_entry::*;
 pub use mode::*;
 pub use mouse_action::*;
 pub use mouse_button::*;
@@ -94,13 +118,17 @@ pub use option_infos::*;
 pub use parsed_viml_expression::*;
 pub use paste_phase::*;
 pub use proc_infos::*;
+pub use process_info::*;
 #[cfg(feature = "neovim-nightly")] // On Nightly.
 pub use progress_message_status::ProgressMessageStatus;
 pub use register_type::*;
+pub use sign_definition::*;
+pub use split_command::*;
 pub use split_direction::*;
 pub use split_modifier::*;
 pub use statusline_highlight_infos::*;
 pub use statusline_infos::*;
+pub use syn_attr::*;
 pub use ui_infos::*;
 pub use viml_ast_node::*;
 pub use virt_lines_overflow::VirtLinesOverflow;
@@ -114,3 +142,5 @@ pub use window_relative_to::*;
 pub use window_style::*;
 pub use window_title::*;
 pub use window_title_position::*;
+
+pub use crate::generated::{BufOption, OptionName};
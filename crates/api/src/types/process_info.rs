@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use types::{
+    Object,
+    conversion::{self, FromObject},
+    serde::Deserializer,
+};
+
+/// Informations about a running process, returned by
+/// [`get_process_info()`](crate::get_process_info).
+#[non_exhaustive]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize)]
+pub struct ProcessInfo {
+    /// The name of the process' executable.
+    pub name: String,
+
+    /// The process id.
+    pub pid: u32,
+
+    /// The id of the parent process.
+    pub ppid: u32,
+
+    /// The id of the user owning the process, if Neovim reports it. Only
+    /// Unix systems have one, and `nvim_get_proc()` currently doesn't include
+    /// it on any platform, so this is always `None` for now.
+    #[serde(default)]
+    pub uid: Option<u32>,
+}
+
+impl FromObject for ProcessInfo {
+    fn from_object(obj: Object) -> Result<Self, conversion::Error> {
+        Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
+    }
+}
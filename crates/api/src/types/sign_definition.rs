@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use types::{
+    Object,
+    conversion::{self, FromObject},
+    serde::Deserializer,
+};
+
+/// A sign type defined via `:sign define` or `sign_define()`, returned by
+/// [`sign_getdefined()`](crate::sign_getdefined).
+///
+/// The highlight groups are empty strings if they're not set.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Deserialize)]
+pub struct SignDefinition {
+    /// The name of the sign.
+    pub name: String,
+
+    /// The text displayed in the sign column.
+    #[serde(default)]
+    pub text: String,
+
+    /// The highlight group used for the text.
+    #[serde(default)]
+    pub texthl: String,
+
+    /// The highlight group used for the whole line the sign is placed on.
+    #[serde(default)]
+    pub linehl: String,
+
+    /// The highlight group used for the line number.
+    #[serde(default)]
+    pub numhl: String,
+
+    /// The highlight group used for the text when the cursor is on the same
+    /// line and `'cursorline'` is set.
+    #[serde(default)]
+    pub culhl: String,
+
+    /// The default priority of the signs of this type, if one was given when
+    /// defining it.
+    #[serde(default)]
+    pub priority: Option<u32>,
+}
+
+impl FromObject for SignDefinition {
+    fn from_object(obj: Object) -> Result<Self, conversion::Error> {
+        Self::deserialize(Deserializer::new(obj)).map_err(Into::into)
+    }
+}
@@ -0,0 +1,45 @@
+/// The kind of split created by [`split()`](crate::split).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SplitCommand {
+    /// Splits the window horizontally (`:split`), placing the new window
+    /// according to the `'splitbelow'` option.
+    Horizontal,
+
+    /// Splits the window vertically (`:vsplit`), placing the new window
+    /// according to the `'splitright'` option.
+    Vertical,
+
+    /// Splits the window horizontally, placing the new window above the
+    /// current one (`:aboveleft split`).
+    Above,
+
+    /// Splits the window horizontally, placing the new window below the
+    /// current one (`:belowright split`).
+    Below,
+
+    /// Splits the window vertically, placing the new window to the left of
+    /// the current one (`:aboveleft vsplit`).
+    Left,
+
+    /// Splits the window vertically, placing the new window to the right of
+    /// the current one (`:belowright vsplit`).
+    Right,
+}
+
+impl SplitCommand {
+    #[inline]
+    pub(crate) fn is_vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Left | Self::Right)
+    }
+
+    #[inline]
+    pub(crate) fn modifier(self) -> Option<super::SplitModifier> {
+        use super::SplitModifier;
+
+        match self {
+            Self::Horizontal | Self::Vertical => None,
+            Self::Above | Self::Left => Some(SplitModifier::AboveLeft),
+            Self::Below | Self::Right => Some(SplitModifier::BelowRight),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/// An attribute of a syntax group, queried via
+/// [`syn_id_attr()`](crate::syn_id_attr).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SynAttr {
+    /// The name of the syntax group.
+    Name,
+
+    /// The foreground color.
+    Fg,
+
+    /// The background color.
+    Bg,
+
+    /// The special color, used for undercurls and underlines.
+    Sp,
+
+    /// `"1"` if the group is bold.
+    Bold,
+
+    /// `"1"` if the group is italic.
+    Italic,
+
+    /// `"1"` if the group is underlined.
+    Underline,
+
+    /// `"1"` if the group is reversed.
+    Reverse,
+}
+
+impl SynAttr {
+    #[inline]
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Fg => "fg",
+            Self::Bg => "bg",
+            Self::Sp => "sp",
+            Self::Bold => "bold",
+            Self::Italic => "italic",
+            Self::Underline => "underline",
+            Self::Reverse => "reverse",
+        }
+    }
+}
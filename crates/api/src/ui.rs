@@ -0,0 +1,69 @@
+//! Functions to inspect the state of the UI.
+
+use crate::Result;
+use crate::TabPage;
+use crate::opts::OptionOpts;
+
+/// The size of the editor and of the parts of the screen that aren't
+/// available to the windows, captured by [`UiInfoSnapshot::capture()`].
+///
+/// All the sizes are in screen cells, and they're only valid at the time the
+/// snapshot was taken: they change when the user resizes the terminal or
+/// sets one of the options they're read from.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct UiInfoSnapshot {
+    /// The width of the screen, i.e. the `'columns'` option.
+    pub columns: u32,
+
+    /// The height of the screen, i.e. the `'lines'` option.
+    pub lines: u32,
+
+    /// The height of the command-line area, i.e. the `'cmdheight'` option.
+    pub cmdheight: u32,
+
+    /// The height of the tabline, which is 1 if it's shown according to the
+    /// `'showtabline'` option and 0 otherwise.
+    pub tabline_height: u32,
+
+    /// The height of the statusline of the current window, which is 1 if
+    /// it's shown according to the `'laststatus'` option and 0 otherwise.
+    pub statusline_height: u32,
+}
+
+impl UiInfoSnapshot {
+    /// Reads the sizes of the UI from the current options and window layout.
+    pub fn capture() -> Result<Self> {
+        let opts = OptionOpts::default();
+        let option = |name| crate::get_option_value::<u32>(name, &opts);
+
+        let tabline_height = match option("showtabline")? {
+            0 => false,
+            1 => crate::list_tabpages().nth(1).is_some(),
+            _ => true,
+        };
+
+        let statusline_height = match option("laststatus")? {
+            0 => false,
+            1 => has_split_windows()?,
+            _ => true,
+        };
+
+        Ok(Self {
+            columns: option("columns")?,
+            lines: option("lines")?,
+            cmdheight: option("cmdheight")?,
+            tabline_height: tabline_height.into(),
+            statusline_height: statusline_height.into(),
+        })
+    }
+}
+
+/// Returns whether the current tabpage contains more than one window, not
+/// counting floating windows.
+fn has_split_windows() -> Result<bool> {
+    let mut split_windows = TabPage::current().list_wins()?.filter(|win| {
+        win.get_config().is_ok_and(|config| config.relative.is_none())
+    });
+    Ok(split_windows.nth(1).is_some())
+}
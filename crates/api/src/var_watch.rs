@@ -0,0 +1,88 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use types::conversion::FromObject;
+
+use crate::Buffer;
+use crate::Result;
+use crate::opts::BufAttachOpts;
+
+/// A handle to a watch registered via [`Buffer::watch_var`]. The watch is
+/// cancelled when this is dropped.
+#[must_use = "the watch is cancelled as soon as the token is dropped"]
+#[derive(Debug)]
+pub struct WatchToken {
+    is_cancelled: Rc<Cell<bool>>,
+}
+
+impl WatchToken {
+    /// Cancels the watch. This is equivalent to dropping the token.
+    #[inline]
+    pub fn cancel(self) {}
+}
+
+impl Drop for WatchToken {
+    #[inline]
+    fn drop(&mut self) {
+        self.is_cancelled.set(true);
+    }
+}
+
+impl Buffer {
+    /// Watches the buffer-local variable `b:{name}`, calling `callback` with
+    /// its old and new values every time it changes. A value is `None` if the
+    /// variable is not set or if it can't be converted into a `T`.
+    ///
+    /// This is built on top of [`Buffer::attach`], so the variable is only
+    /// checked when the buffer's `b:changedtick` is incremented (e.g. when
+    /// its lines are edited), and not as soon as the variable is set.
+    ///
+    /// The watch is cancelled when the returned [`WatchToken`] is dropped,
+    /// after which the callbacks are detached on the next buffer change.
+    pub fn watch_var<T, F>(
+        &self,
+        name: &str,
+        callback: F,
+    ) -> Result<WatchToken>
+    where
+        T: FromObject + PartialEq + Clone + 'static,
+        F: FnMut(Option<T>, Option<T>) + 'static,
+    {
+        let is_cancelled = Rc::new(Cell::new(false));
+
+        let on_change = {
+            let is_cancelled = Rc::clone(&is_cancelled);
+            let buffer = self.clone();
+            let name = name.to_owned();
+            let value = RefCell::new(buffer.get_var::<T>(&name).ok());
+            let callback = RefCell::new(callback);
+
+            Rc::new(move || {
+                if is_cancelled.get() {
+                    return true;
+                }
+
+                let new = buffer.get_var::<T>(&name).ok();
+
+                if *value.borrow() != new {
+                    let old = value.replace(new.clone());
+                    (callback.borrow_mut())(old, new);
+                }
+
+                false
+            })
+        };
+
+        let opts = BufAttachOpts::builder()
+            .on_lines({
+                let on_change = Rc::clone(&on_change);
+                move |_args| on_change()
+            })
+            .on_changedtick(move |_args| on_change())
+            .build();
+
+        self.attach(false, &opts)?;
+
+        Ok(WatchToken { is_cancelled })
+    }
+}
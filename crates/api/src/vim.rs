@@ -1,5 +1,7 @@
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
+use luajit::{self as lua, Poppable, Pushable};
 use types::NvimStr;
 use types::{
     self as nvim,
@@ -19,6 +21,38 @@ use crate::types::*;
 use crate::{Buffer, TabPage, Window};
 use crate::{Error, Result};
 
+/// Returns the version of the running Neovim instance and of its API, from
+/// [`nvim_get_api_info()`][1].
+///
+/// The version can't change while Neovim is running, so it's only fetched
+/// the first time this is called, and every later call returns the cached
+/// value. The first call has to happen on Neovim's main thread, like any
+/// other API call, but the returned reference can then be shared freely. An
+/// error is returned, and nothing is cached, if the version can't be read
+/// from the API metadata.
+///
+/// [1]: https://neovim.io/doc/user/api.html#nvim_get_api_info()
+pub fn api_version() -> Result<&'static ApiVersion> {
+    static VERSION: OnceLock<ApiVersion> = OnceLock::new();
+
+    if let Some(version) = VERSION.get() {
+        return Ok(version);
+    }
+
+    let info = unsafe { nvim_get_api_info(LUA_INTERNAL_CALL, types::arena()) };
+    let metadata = info.into_iter().nth(1).ok_or_else(|| {
+        Error::custom("nvim_get_api_info() returned no metadata")
+    })?;
+    let version =
+        Dictionary::from_object(metadata)?
+            .get("version")
+            .cloned()
+            .ok_or_else(|| Error::custom("the API metadata has no version"))?;
+    let version = ApiVersion::from_object(version)?;
+
+    Ok(VERSION.get_or_init(|| version))
+}
+
 /// Binding to [`nvim_chan_send()`][1].
 ///
 /// Sends data to a channel.
@@ -213,6 +247,20 @@ pub fn err_writeln(str: &str) {
     unsafe { nvim_err_writeln(nvim::String::from(str).as_nvim_str()) }
 }
 
+/// Returns the current values of `v:errmsg`, `v:exception` and
+/// `v:throwpoint`, fetched via [`get_vvar()`].
+///
+/// This is useful after calling Vimscript functions that report failures by
+/// setting `v:errmsg` instead of throwing. Set `v:errmsg` to an empty string
+/// with [`set_vvar()`] beforehand to tell a new error from an older one.
+pub fn error_vars() -> Result<ErrorVars> {
+    Ok(ErrorVars {
+        errmsg: get_vvar("errmsg")?,
+        exception: get_vvar("exception")?,
+        throwpoint: get_vvar("throwpoint")?,
+    })
+}
+
 /// Binding to [`nvim_eval_statusline()`][1].
 ///
 /// Evaluates a string to be displayed in the statusline.
@@ -230,6 +278,61 @@ pub fn eval_statusline(
     choose!(err, Ok(StatuslineInfos::from_object(dict.into())?))
 }
 
+/// Binding to [`nvim_exec_lua()`][1].
+///
+/// Executes a Lua chunk. The `args` are passed to the chunk as variadic
+/// arguments, and can be accessed from it via `...`. The first value returned
+/// by the chunk is then popped off the stack as an `R`, and any other value
+/// is discarded. Return a table to get more than one value back.
+///
+/// Unlike the Neovim function, this loads and calls the chunk directly on the
+/// Lua state, so `args` and the return value don't have to go through an
+/// [`Object`] conversion.
+///
+/// [1]: https://neovim.io/doc/user/api.html#nvim_exec_lua()
+pub fn exec_lua<A, R>(code: &str, args: A) -> Result<R>
+where
+    A: Pushable,
+    R: Poppable,
+{
+    unsafe {
+        lua::with_state(move |lstate| {
+            let height = lua::ffi::lua_gettop(lstate);
+
+            let mut status = lua::ffi::luaL_loadbuffer(
+                lstate,
+                code.as_ptr() as *const _,
+                code.len(),
+                lua::macros::cstr!("=exec_lua"),
+            );
+
+            if status == lua::ffi::LUA_OK {
+                if let Err(err) = lua::utils::reserve_stack(lstate) {
+                    lua::ffi::lua_settop(lstate, height);
+                    return Err(err.into());
+                }
+                let nargs = args.push(lstate);
+                status = lua::ffi::lua_pcall(lstate, nargs, 1, 0);
+                if status == lua::ffi::LUA_OK {
+                    let res = R::pop(lstate);
+                    // Popping can fail without consuming the value.
+                    lua::ffi::lua_settop(lstate, height);
+                    return res.map_err(Into::into);
+                }
+            }
+
+            // If the chunk was loaded this is the status of the call.
+            let msg = lua::utils::pop_error_message(lstate);
+
+            Err(match status {
+                lua::ffi::LUA_ERRSYNTAX => Error::LuaSyntaxError(msg),
+                lua::ffi::LUA_ERRMEM => lua::Error::MemoryError(msg).into(),
+                _ => lua::Error::RuntimeError(msg).into(),
+            })
+        })
+    }
+}
+
 /// Binding to [`nvim_feedkeys()`][1].
 ///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_feedkeys()
@@ -368,6 +471,34 @@ pub fn get_hl(
     }
 }
 
+/// Returns the names of all the highlight groups defined in the namespace
+/// `ns_id`, sorted alphabetically. The global namespace `0` contains all the
+/// groups defined via `:highlight`, including the default ones.
+///
+/// This calls [`nvim_get_hl()`][1] without deserializing the definitions of
+/// the groups.
+///
+/// [1]: https://neovim.io/doc/user/api.html#nvim_get_hl()
+pub fn get_hl_groups(ns_id: u32) -> Result<Vec<String>> {
+    let mut err = nvim::Error::new();
+    let dict = unsafe {
+        nvim_get_hl(
+            ns_id as Integer,
+            &GetHighlightOpts::default(),
+            types::arena(),
+            &mut err,
+        )
+    };
+    choose!(err, {
+        let mut names = dict
+            .into_iter()
+            .map(|(name, _)| name.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        Ok(names)
+    })
+}
+
 /// Binding to [`nvim_get_hl_id_by_name()`][1].
 ///
 /// Gets a highlight definition by name.
@@ -468,10 +599,49 @@ pub fn get_proc_children(pid: u32) -> Result<impl SuperIterator<u32> + use<>> {
     )
 }
 
+/// Binding to [`nvim_get_proc()`][1].
+///
+/// Gets informations about the process with the given `pid`, or `None` if no
+/// such process exists.
+///
+/// Everything goes through Neovim, so this always inspects the processes
+/// visible to the running Neovim instance, even when it's embedded.
+///
+/// [1]: https://neovim.io/doc/user/api.html#nvim_get_proc()
+pub fn get_process_info(pid: u32) -> Result<Option<ProcessInfo>> {
+    let mut err = nvim::Error::new();
+    let obj = unsafe { nvim_get_proc(pid.into(), types::arena(), &mut err) };
+
+    if err.is_err() {
+        return Err(err.into());
+    }
+
+    // Neovim returns `nil` (or an empty dictionary on some platforms) if the
+    // process doesn't exist.
+    let not_found = match obj.kind() {
+        types::ObjectKind::Nil => true,
+        types::ObjectKind::Dictionary => {
+            unsafe { obj.as_dictionary_unchecked() }.is_empty()
+        },
+        _ => false,
+    };
+
+    if not_found {
+        return Ok(None);
+    }
+
+    Ok(Some(ProcessInfo::from_object(obj)?))
+}
+
 /// Binding to [`nvim_get_runtime_file()`][1].
 ///
 /// Returns an iterator over all the files matching `name` in the runtime path.
 ///
+/// `name` can contain wildcards, including `**` to match any number of
+/// directories (e.g. `"lua/**/*.lua"`). It's passed verbatim to Neovim, which
+/// expands it relative to each directory in `'runtimepath'`. If `get_all` is
+/// `false` only the first match is returned.
+///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_get_runtime_file()
 pub fn get_runtime_file<T: AsRef<Path>>(
     name: T,
@@ -497,6 +667,18 @@ pub fn get_runtime_file<T: AsRef<Path>>(
     )
 }
 
+/// Returns the tabpage with the given 1-based number, i.e. the one whose
+/// [`TabPage::get_number()`] is `number`, or `None` if there's no such
+/// tabpage.
+pub fn get_tabpage_by_number(number: u32) -> Result<Option<TabPage>> {
+    for tabpage in list_tabpages() {
+        if tabpage.get_number()? == number {
+            return Ok(Some(tabpage));
+        }
+    }
+    Ok(None)
+}
+
 /// Binding to [`nvim_get_var()`][1].
 ///
 /// Gets a global (`g:`) variable.
@@ -583,6 +765,26 @@ pub fn input_mouse(
     choose!(err, ())
 }
 
+/// Returns a human-readable representation of `value` using
+/// [`vim.inspect()`][1]. Useful for debugging, e.g. to print the contents of
+/// a nested table. If `value` pushes multiple values onto the Lua stack, only
+/// the first one is inspected.
+///
+/// [1]: https://neovim.io/doc/user/lua.html#vim.inspect()
+pub fn inspect<V: Pushable>(value: V) -> Result<String> {
+    exec_lua("return vim.inspect((...))", value)
+}
+
+/// Links the highlight group `from` to `to`, like `:hi link`. This is a
+/// shorthand for calling [`set_hl()`] with only the
+/// [`link`](SetHighlightOptsBuilder::link) attribute set.
+pub fn link_hl(from: &str, to: &str, opts: &LinkHlOpts) -> Result<()> {
+    let to = crate::HlGroup::to_hl_id(&to)?;
+    let hl_opts =
+        SetHighlightOpts::builder().link(to).force(opts.force).build();
+    set_hl(opts.ns_id, from, &hl_opts)
+}
+
 /// Binding to [`nvim_list_bufs()`][1].
 ///
 /// Gets the current list of [`Buffer`]s, including [unlisted][2]
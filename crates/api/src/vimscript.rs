@@ -3,7 +3,7 @@ use types::{self as nvim, Array, Object, conversion::FromObject};
 use crate::Result;
 use crate::choose;
 use crate::ffi::vimscript::*;
-use crate::opts::ExecOpts;
+use crate::opts::{ExecOpts, GetMessagesOpts};
 use crate::types::*;
 
 /// Binding to [`nvim_call_dict_function()`][1].
@@ -97,6 +97,10 @@ where
 ///
 /// Unlike [`command`] this function supports heredocs, script-scope (s:), etc.
 ///
+/// When `output` is set in `opts`, Neovim collects all the output into a
+/// single string before returning it, so there's no way to stream it
+/// incrementally. See [`exec2_output`] for a shorthand capturing the output.
+///
 /// [1]: https://neovim.io/doc/user/api.html#nvim_exec2()
 pub fn exec2(src: &str, opts: &ExecOpts) -> Result<Option<nvim::String>> {
     let src = types::String::from(src);
@@ -112,6 +116,39 @@ pub fn exec2(src: &str, opts: &ExecOpts) -> Result<Option<nvim::String>> {
     })
 }
 
+/// Executes Vimscript like [`exec2`], returning its captured output. The
+/// output is empty if the commands didn't produce any.
+///
+/// Note that the whole output is kept in memory, as Neovim doesn't support
+/// streaming it (e.g. for `:scriptnames` or `:digraphs`).
+pub fn exec2_output(src: &str) -> Result<nvim::String> {
+    let opts = ExecOpts::builder().output(true).build();
+    Ok(exec2(src, &opts)?.unwrap_or_default())
+}
+
+/// Returns the entries in the message history, from the oldest to the most
+/// recent one.
+///
+/// This captures the output of [`:messages`][1] via [`exec2_output`], since
+/// Neovim doesn't provide a structured API to access the message history
+/// outside of UIs attached with `ext_messages`. As a consequence each line of
+/// the output becomes a separate entry, even when it was part of a multiline
+/// message.
+///
+/// [1]: https://neovim.io/doc/user/message.html#%3Amessages
+pub fn get_messages(opts: &GetMessagesOpts) -> Result<Vec<MessageEntry>> {
+    let src = match opts.count {
+        Some(count) => format!("{count}messages"),
+        None => "messages".to_owned(),
+    };
+    Ok(exec2_output(&src)?
+        .to_string_lossy()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| MessageEntry { content: line.to_owned() })
+        .collect())
+}
+
 /// Binding to [`nvim_parse_expression()`][1].
 ///
 /// Parses a VimL expression.
@@ -25,7 +25,9 @@ pub fn open_win(
 impl Window {
     /// Binding to [`nvim_win_get_config()`][1].
     ///
-    /// Gets the window configuration.
+    /// Gets the window configuration. The returned config can be modified and
+    /// passed back to [`set_config()`](Window::set_config), e.g. to resize a
+    /// floating window without having to repeat its position.
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_win_get_config()
     pub fn get_config(&self) -> Result<WindowConfig> {
@@ -34,16 +36,14 @@ impl Window {
         let out =
             unsafe { nvim_win_get_config(self.0, types::arena(), &mut err) };
 
-        let out = WindowConfig::try_from(out)?;
-
-        choose!(err, Ok(out))
+        choose!(err, Ok(WindowConfig::try_from(out)?))
     }
 
-    /// Binding to [`nvim_win_get_config()`][1].
+    /// Binding to [`nvim_win_set_config()`][1].
     ///
     /// Configures the window layout. Only for floating and external windows.
     ///
-    /// [1]: https://neovim.io/doc/user/api.html#nvim_win_get_config()
+    /// [1]: https://neovim.io/doc/user/api.html#nvim_win_set_config()
     pub fn set_config(&mut self, config: &WindowConfig) -> Result<()> {
         let mut err = nvim::Error::new();
         unsafe { nvim_win_set_config(self.0, &config.into(), &mut err) };
@@ -17,9 +17,17 @@ use types::{
 use crate::Result;
 use crate::choose;
 use crate::ffi::window::*;
-use crate::opts::WinTextHeightOpts;
-use crate::types::WinTextHeightInfos;
-use crate::{Buffer, IntoResult, TabPage};
+use crate::opts::{CmdOpts, GetNamespaceOpts, SplitOpts, WinTextHeightOpts};
+use crate::types::{
+    CmdInfos,
+    CmdRange,
+    CommandModifiers,
+    CursorPosition,
+    LogLevel,
+    SplitCommand,
+    WinTextHeightInfos,
+};
+use crate::{Buffer, CallContext, IntoResult, TabPage};
 
 /// A wrapper around a Neovim window handle.
 #[derive(Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -105,6 +113,8 @@ impl Window {
     /// Binding to [`nvim_win_call()`][1].
     ///
     /// Calls a function with this window as the temporary current window.
+    /// Inside the function, [`CallContext`] can be used to get the targeted
+    /// window.
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_win_call()
     pub fn call<F, Res, Ret>(&self, fun: F) -> Result<Ret>
@@ -114,7 +124,11 @@ impl Window {
         Res::Error: StdError + 'static,
         Ret: Pushable + FromObject,
     {
-        let fun = Function::from_fn_once(fun);
+        let target = self.clone();
+        let fun = Function::from_fn_once(move |args| {
+            let _frame = CallContext::enter_win(target);
+            fun(args)
+        });
         let mut err = nvim::Error::new();
 
         let ref_or_nil =
@@ -153,6 +167,11 @@ impl Window {
     /// Closes the window. Not allowed when
     /// [`textlock`](https://neovim.io/doc/user/eval.html#textlock) is active.
     ///
+    /// The window is taken by value so that its handle can't be used after
+    /// it's been closed. Returns an error when trying to close the last
+    /// window, or if `force` is `false`, `'hidden'` is not set and the window
+    /// is the last one displaying a buffer with unsaved changes.
+    ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_win_close()
     pub fn close(self, force: bool) -> Result<()> {
         let mut err = nvim::Error::new();
@@ -200,6 +219,19 @@ impl Window {
         })
     }
 
+    /// Like [`get_cursor()`](Window::get_cursor), but returns a
+    /// [`CursorPosition`] that provides the row as both 0- and 1-indexed and
+    /// the column as both a byte and a character offset.
+    pub fn get_cursor_position(&self) -> Result<CursorPosition> {
+        let (row, col) = self.get_cursor()?;
+        let line = self
+            .get_buf()?
+            .get_lines(row - 1..row, true)?
+            .next()
+            .unwrap_or_default();
+        Ok(CursorPosition::new(row, col, line.as_bytes()))
+    }
+
     /// Binding to [`nvim_win_get_height()`][1].
     ///
     /// Gets the window height as a count of rows.
@@ -211,6 +243,30 @@ impl Window {
         choose!(err, Ok(height.try_into().expect("always positive")))
     }
 
+    /// Binding to [`nvim_get_hl_ns()`][1] with the `winid` option set to
+    /// this window.
+    ///
+    /// Gets the highlight namespace set for this window via
+    /// [`set_hl_ns`](Window::set_hl_ns), or `None` if no namespace has been
+    /// set. Together with [`set_hl_ns`](Window::set_hl_ns) this can be used
+    /// to temporarily override a window's highlights and restore them
+    /// afterwards.
+    ///
+    /// [1]: https://neovim.io/doc/user/api.html#nvim_get_hl_ns()
+    pub fn get_hl_ns(&self) -> Result<Option<u32>> {
+        let opts = GetNamespaceOpts::builder().winid(self.clone()).build();
+        let mut err = nvim::Error::new();
+        let ns_id =
+            unsafe { crate::ffi::vim::nvim_get_hl_ns(&opts, &mut err) };
+        choose!(
+            err,
+            match ns_id {
+                -1 => Ok(None),
+                other => Ok(Some(other.try_into()?)),
+            }
+        )
+    }
+
     /// Binding to [`nvim_win_get_number()`][1].
     ///
     /// Gets the window number.
@@ -252,7 +308,8 @@ impl Window {
 
     /// Binding to [`nvim_win_get_var()`][1].
     ///
-    /// Gets a window-scoped (`w:`) variable.
+    /// Gets a window-scoped (`w:`) variable. Returns an error if the variable
+    /// isn't set, e.g. after removing it with [`del_var`](Window::del_var).
     ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_win_get_var()
     pub fn get_var<Var>(&self, name: &str) -> Result<Var>
@@ -298,6 +355,10 @@ impl Window {
     ///
     /// Checks if the window is valid.
     ///
+    /// A window becomes invalid as soon as it's closed, whether via
+    /// [`close`](Self::close) or by an Ex command like `:quit`. Since Neovim
+    /// never reuses window handles, it never becomes valid again afterwards.
+    ///
     /// [1]: https://neovim.io/doc/user/api.html#nvim_win_is_valid()
     pub fn is_valid(&self) -> bool {
         unsafe { nvim_win_is_valid(self.0) }
@@ -386,6 +447,38 @@ impl Window {
         choose!(err, ())
     }
 
+    /// Shows `msg` in the message area using the highlight group matching
+    /// `level`, i.e. `ErrorMsg` for errors, `WarningMsg` for warnings and
+    /// `ModeMsg` for infos. Lower levels use the default highlighting, and
+    /// nothing is shown for [`LogLevel::Off`].
+    ///
+    /// The message area is shared by all the windows, so this is a shorthand
+    /// for calling [`nvim_echo()`][1] with a single chunk from code that
+    /// works with a window, e.g. inside [`call()`](Self::call). The message is
+    /// also added to the message history.
+    ///
+    /// [1]: https://neovim.io/doc/user/api.html#nvim_echo()
+    pub fn show_message(&self, msg: &str, level: LogLevel) -> Result<()> {
+        if level == LogLevel::Off {
+            return Ok(());
+        }
+        let hl_group = level.message_hl_group().map(nvim::String::from);
+        let chunk =
+            Array::from_iter([Object::from(msg), Object::from(hl_group)]);
+        let chunks = Array::from_iter([chunk]);
+        let opts = crate::opts::EchoOpts::default();
+        let mut err = nvim::Error::new();
+        unsafe {
+            crate::ffi::vim::nvim_echo(
+                chunks.non_owning(),
+                true,
+                &opts,
+                &mut err,
+            )
+        };
+        choose!(err, ())
+    }
+
     /// Binding to [`nvim_win_text_height()`][1].
     ///
     /// Computes the number of screen lines occupied by a range of text in a
@@ -403,3 +496,41 @@ impl Window {
         choose!(err, dict.try_into().map_err(Into::into))
     }
 }
+
+/// Splits the current window using [`nvim_cmd()`][1], returning the new
+/// window, which also becomes the current one.
+///
+/// If `buf` is given it's displayed in the new window, otherwise the new
+/// window shows either the current buffer or, if
+/// [`new`](crate::opts::SplitOptsBuilder::new) is set, a new empty buffer.
+///
+/// [1]: https://neovim.io/doc/user/api.html#nvim_cmd()
+pub fn split(
+    command: SplitCommand,
+    buf: Option<&Buffer>,
+    opts: &SplitOpts,
+) -> Result<Window> {
+    let cmd = match (command.is_vertical(), opts.new && buf.is_none()) {
+        (false, false) => "split",
+        (false, true) => "new",
+        (true, false) => "vsplit",
+        (true, true) => "vnew",
+    };
+
+    let mut mods = CommandModifiers::default();
+    mods.split = opts.mods.or(command.modifier());
+
+    let mut infos = CmdInfos::builder();
+    infos.cmd(cmd).mods(mods);
+    if let Some(size) = opts.size {
+        infos.range(CmdRange::Single(size as usize));
+    }
+
+    crate::cmd(&infos.build(), &CmdOpts::default())?;
+
+    let mut win = crate::get_current_win();
+    if let Some(buf) = buf {
+        win.set_buf(buf)?;
+    }
+    Ok(win)
+}
@@ -22,9 +22,13 @@ pub const fn lua_upvalueindex(i: c_int) -> c_int {
     LUA_GLOBALSINDEX - i
 }
 
+// Option for multiple returns in `lua_pcall` and `lua_call`.
+pub const LUA_MULTRET: c_int = -1;
+
 // Thread status.
 pub const LUA_OK: c_int = 0;
 pub const LUA_ERRRUN: c_int = 2;
+pub const LUA_ERRSYNTAX: c_int = 3;
 pub const LUA_ERRMEM: c_int = 4;
 pub const LUA_ERRERR: c_int = 5;
 
@@ -57,6 +61,9 @@ unsafe extern "C" {
     // https://www.lua.org/manual/5.1/manual.html#lua_call
     pub fn lua_call(L: *mut State, nargs: c_int, nresults: c_int);
 
+    // https://www.lua.org/manual/5.1/manual.html#lua_checkstack
+    pub fn lua_checkstack(L: *mut State, extra: c_int) -> c_int;
+
     /// Binding to [`lua_createtable()`] (-0, +1).
     ///
     /// Creates a new empty table and pushes it onto the stack. The new table
@@ -168,6 +175,14 @@ unsafe extern "C" {
     // https://www.lua.org/manual/5.1/manual.html#luaL_error
     pub fn luaL_error(L: *mut State, fmt: *const c_char, ...) -> !;
 
+    // https://www.lua.org/manual/5.1/manual.html#luaL_loadbuffer
+    pub fn luaL_loadbuffer(
+        L: *mut State,
+        buff: *const c_char,
+        sz: usize,
+        name: *const c_char,
+    ) -> c_int;
+
     // https://www.lua.org/manual/5.1/manual.html#luaL_ref
     pub fn luaL_ref(L: *mut State, t: c_int) -> c_int;
 
@@ -0,0 +1,55 @@
+use core::ffi::c_int;
+
+use crate::ffi::{Integer, State};
+use crate::{Error, Poppable, Pushable};
+
+/// Trait implemented by bitflags-style types that can be converted to and
+/// from their [`Integer`] representation.
+pub trait BitFlags: Copy + Sized {
+    /// Returns the raw bits of the flags.
+    fn bits(self) -> Integer;
+
+    /// Creates the flags from their raw bits, returning `None` if any of the
+    /// bits doesn't correspond to a known flag.
+    fn from_bits(bits: Integer) -> Option<Self>;
+}
+
+/// A wrapper around a [`BitFlags`] type that's pushed onto and popped off the
+/// Lua stack as an integer.
+///
+/// Popping fails if the integer contains bits that are not known to `T`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Flags<T>(pub T);
+
+impl<T> Flags<T> {
+    /// Returns the wrapped flags.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: BitFlags> From<T> for Flags<T> {
+    #[inline]
+    fn from(flags: T) -> Self {
+        Self(flags)
+    }
+}
+
+impl<T: BitFlags> Pushable for Flags<T> {
+    unsafe fn push(self, lstate: *mut State) -> c_int {
+        self.0.bits().push(lstate)
+    }
+}
+
+impl<T: BitFlags> Poppable for Flags<T> {
+    unsafe fn pop(lstate: *mut State) -> Result<Self, Error> {
+        let bits = Integer::pop(lstate)?;
+        T::from_bits(bits).map(Self).ok_or_else(|| {
+            Error::pop_error(
+                std::any::type_name::<T>(),
+                format!("unknown bits in {bits:#b}"),
+            )
+        })
+    }
+}
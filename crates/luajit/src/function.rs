@@ -36,6 +36,7 @@ where
                 let ret = fun(args)
                     .into_result()
                     .map_err(crate::Error::push_error_from_err::<R, _>)?;
+                utils::reserve_stack(lstate)?;
                 Ok(ret.push(lstate))
             };
 
@@ -56,6 +57,7 @@ where
 {
     unsafe {
         crate::with_state(move |lstate| {
+            utils::reserve_stack(lstate)?;
             ffi::lua_rawgeti(lstate, ffi::LUA_REGISTRYINDEX, lua_ref);
             let nargs = args.push(lstate);
 
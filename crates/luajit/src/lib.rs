@@ -2,6 +2,7 @@
 
 mod error;
 pub mod ffi;
+mod flags;
 pub mod function;
 mod into_result;
 pub mod macros;
@@ -11,9 +12,10 @@ mod state;
 pub mod utils;
 
 pub use error::Error;
+pub use flags::{BitFlags, Flags};
 pub use into_result::IntoResult;
 #[doc(hidden)]
 pub use macros::__print;
-pub use poppable::Poppable;
+pub use poppable::{Poppable, ToStringCoerce};
 pub use pushable::Pushable;
 pub use state::{init, with_state};
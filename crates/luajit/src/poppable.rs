@@ -1,5 +1,5 @@
 use core::hash::Hash;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::Error;
 use crate::ffi::*;
@@ -106,6 +106,9 @@ impl Poppable for f32 {
     }
 }
 
+/// Only pops Lua strings, returning an error if the value is a number or if
+/// the string isn't valid UTF-8. Use [`ToStringCoerce`] to also accept
+/// numbers and invalid UTF-8.
 impl Poppable for String {
     unsafe fn pop(state: *mut State) -> Result<Self, Error> {
         if lua_gettop(state) == 0 {
@@ -113,26 +116,52 @@ impl Poppable for String {
         }
 
         match lua_type(state, -1) {
-            LUA_TSTRING | LUA_TNUMBER => {
-                let mut len = 0;
-                let ptr = lua_tolstring(state, -1, &mut len);
-
-                // NOTE: `ptr` should never be null if the value at the top of
-                // the stack is a string or a number.
-                assert!(!ptr.is_null());
+            LUA_TSTRING => {
+                let bytes = pop_bytes(state);
+                String::from_utf8(bytes)
+                    .map_err(Error::pop_error_from_err::<Self, _>)
+            },
+            other => Err(Error::pop_wrong_type::<Self>(LUA_TSTRING, other)),
+        }
+    }
+}
 
-                let slice = std::slice::from_raw_parts(ptr as *const u8, len);
-                let str = String::from_utf8_lossy(slice).to_string();
+/// A [`String`] that's popped leniently, like Lua's own `tostring()` would
+/// for strings and numbers: numbers are converted to strings, and invalid
+/// UTF-8 sequences are replaced with `U+FFFD`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ToStringCoerce(pub String);
 
-                lua_pop(state, 1);
+impl Poppable for ToStringCoerce {
+    unsafe fn pop(state: *mut State) -> Result<Self, Error> {
+        if lua_gettop(state) == 0 {
+            return Err(Error::PopEmptyStack);
+        }
 
-                Ok(str)
+        match lua_type(state, -1) {
+            LUA_TSTRING | LUA_TNUMBER => {
+                let bytes = pop_bytes(state);
+                Ok(Self(String::from_utf8_lossy(&bytes).into_owned()))
             },
             other => Err(Error::pop_wrong_type::<Self>(LUA_TSTRING, other)),
         }
     }
 }
 
+/// Pops the string or number at the top of the stack, returning its bytes.
+unsafe fn pop_bytes(state: *mut State) -> Vec<u8> {
+    let mut len = 0;
+    let ptr = lua_tolstring(state, -1, &mut len);
+
+    // NOTE: `ptr` should never be null if the value at the top of the stack
+    // is a string or a number.
+    assert!(!ptr.is_null());
+
+    let bytes = std::slice::from_raw_parts(ptr as *const u8, len).to_vec();
+    lua_pop(state, 1);
+    bytes
+}
+
 impl<T> Poppable for Option<T>
 where
     T: Poppable,
@@ -185,6 +214,18 @@ where
     }
 }
 
+/// Pops an array-like table as a set. Duplicate elements are silently
+/// deduplicated.
+impl<T> Poppable for HashSet<T>
+where
+    T: Poppable + Eq + Hash,
+{
+    #[inline]
+    unsafe fn pop(state: *mut State) -> Result<Self, Error> {
+        <Vec<T> as Poppable>::pop(state).map(|vec| vec.into_iter().collect())
+    }
+}
+
 impl<K, V> Poppable for HashMap<K, V>
 where
     K: Poppable + Eq + Hash,
@@ -238,7 +279,7 @@ macro_rules! pop_tuple {
         {
             #[allow(non_snake_case)]
             unsafe fn pop(state: *mut State) -> Result<Self, crate::Error> {
-                crate::utils::grow_stack(state, count!($($name)*));
+                crate::utils::grow_stack(state, count!($($name)*))?;
                 pop_reverse!(state, $($name)*);
                 Ok(($($name,)*))
             }
@@ -1,8 +1,9 @@
 use core::ffi::{c_char, c_int};
+use std::collections::HashSet;
 
 use crate::ffi::{self, Integer, Number, State};
 use crate::macros::count;
-use crate::utils::{self, push_error};
+use crate::utils::push_error;
 
 /// Trait implemented for types that can be pushed onto the Lua stack.
 pub trait Pushable {
@@ -99,6 +100,17 @@ impl Pushable for String {
     }
 }
 
+impl Pushable for &str {
+    unsafe fn push(self, lstate: *mut State) -> c_int {
+        ffi::lua_pushlstring(
+            lstate,
+            self.as_ptr() as *const c_char,
+            self.len(),
+        );
+        1
+    }
+}
+
 impl<T> Pushable for Option<T>
 where
     T: Pushable,
@@ -116,15 +128,34 @@ where
     T: Pushable,
 {
     unsafe fn push(self, lstate: *mut State) -> c_int {
-        ffi::lua_createtable(lstate, self.len() as _, 0);
+        push_array(lstate, self.into_iter())
+    }
+}
 
-        for (i, obj) in self.into_iter().enumerate() {
-            obj.push(lstate);
-            ffi::lua_rawseti(lstate, -2, (i + 1) as _);
-        }
+/// Pushes the set as an array-like table, in no particular order.
+impl<T> Pushable for HashSet<T>
+where
+    T: Pushable,
+{
+    unsafe fn push(self, lstate: *mut State) -> c_int {
+        push_array(lstate, self.into_iter())
+    }
+}
 
-        1
+/// Pushes the items of an iterator as an array-like table.
+unsafe fn push_array<T, I>(lstate: *mut State, items: I) -> c_int
+where
+    T: Pushable,
+    I: ExactSizeIterator<Item = T>,
+{
+    ffi::lua_createtable(lstate, items.len() as _, 0);
+
+    for (i, obj) in items.enumerate() {
+        obj.push(lstate);
+        ffi::lua_rawseti(lstate, -2, (i + 1) as _);
     }
+
+    1
 }
 
 impl<T, E> Pushable for Result<T, E>
@@ -1,16 +1,42 @@
 use core::ffi::{CStr, c_int};
 use core::fmt::Display;
 
+use crate::Error;
 use crate::ffi::{self, State};
 
 /// Does nothing if the stack is already taller than `n`, grows the stack
-/// height to `n` by adding `nil`s if it's not.
-pub unsafe fn grow_stack(lstate: *mut State, n: c_int) {
-    unsafe {
-        if ffi::lua_gettop(lstate) < n {
-            ffi::lua_settop(lstate, n);
-        }
+/// height to `n` by adding `nil`s if it's not. Returns an error if the stack
+/// can't be grown that much.
+pub unsafe fn grow_stack(lstate: *mut State, n: c_int) -> Result<(), Error> {
+    let height = ffi::lua_gettop(lstate);
+
+    if height >= n {
+        return Ok(());
+    }
+
+    if ffi::lua_checkstack(lstate, n - height) == 0 {
+        return Err(Error::RuntimeError(format!(
+            "stack overflow: couldn't grow the stack to {n} values"
+        )));
     }
+
+    ffi::lua_settop(lstate, n);
+
+    Ok(())
+}
+
+/// The number of free stack slots reserved before pushing a value, which is
+/// enough for the largest tuple plus a few levels of nested tables.
+const RESERVED_STACK_SLOTS: c_int = 32;
+
+/// Makes sure there's room on the stack for pushing a value, since
+/// `Pushable`s assume there is and pushing past the end of the stack corrupts
+/// memory.
+pub unsafe fn reserve_stack(lstate: *mut State) -> Result<(), Error> {
+    let height = ffi::lua_gettop(lstate);
+    grow_stack(lstate, height + RESERVED_STACK_SLOTS)?;
+    ffi::lua_settop(lstate, height);
+    Ok(())
 }
 
 /// Returns a displayable representation of the Lua value at a given stack
@@ -72,6 +98,24 @@ pub unsafe fn debug_stack(lstate: *mut State) {
     crate::print!("{stack_pp}");
 }
 
+/// Pops the error object at the top of the stack, returning its string
+/// representation.
+pub unsafe fn pop_error_message(lstate: *mut State) -> String {
+    let mut len = 0;
+    let ptr = ffi::lua_tolstring(lstate, -1, &mut len);
+
+    let msg = if ptr.is_null() {
+        format!("(error object is a {} value)", debug_type(lstate, -1))
+    } else {
+        let bytes = core::slice::from_raw_parts(ptr as *const u8, len);
+        String::from_utf8_lossy(bytes).into_owned()
+    };
+
+    ffi::lua_pop(lstate, 1);
+
+    msg
+}
+
 pub unsafe fn push_error<E: core::fmt::Display + ?Sized>(
     err: &E,
     lstate: *mut State,
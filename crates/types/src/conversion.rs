@@ -199,6 +199,8 @@ impl FromObject for String {
     }
 }
 
+/// Nil objects are converted to `None`, all the others to `Some` if the
+/// conversion to `T` succeeds.
 impl<T> FromObject for Option<T>
 where
     T: FromObject,
@@ -311,3 +313,15 @@ where
             .map(Into::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn option_from_object() {
+        assert_eq!(Ok(None), Option::<Integer>::from_object(Object::nil()));
+        assert_eq!(Ok(Some(42)), Option::<Integer>::from_object(42.into()));
+        assert!(Option::<Integer>::from_object("foo".into()).is_err());
+    }
+}
@@ -9,6 +9,29 @@ use crate::{NonOwning, Object, ObjectKind, conversion};
 #[repr(transparent)]
 pub struct Dictionary(pub(super) KVec<KeyValuePair>);
 
+/// The strategy used by [`Dictionary::merge`] to resolve keys present in both
+/// dictionaries.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum MergeStrategy {
+    /// The value from the other dictionary replaces the existing one.
+    #[default]
+    Overwrite,
+
+    /// The existing value is kept and the one from the other dictionary is
+    /// discarded.
+    KeepExisting,
+
+    /// Like [`Overwrite`](MergeStrategy::Overwrite), except that when both
+    /// values are dictionaries they're merged recursively instead of being
+    /// replaced.
+    ///
+    /// If only one of the two values is a dictionary, the value from the
+    /// other dictionary wins, just like with
+    /// [`Overwrite`](MergeStrategy::Overwrite). This matches the behavior of
+    /// `vim.tbl_deep_extend("force", ..)`.
+    DeepMerge,
+}
+
 /// A key-value pair mapping a [`String`] to an [`Object`].
 //
 // https://github.com/neovim/neovim/blob/v0.9.0/src/nvim/api/private/defs.h#L122-L125
@@ -127,6 +150,43 @@ impl Dictionary {
         self.iter().map(|(key, _)| key)
     }
 
+    /// Merges the key-value pairs of `other` into this dictionary, using
+    /// `strategy` to resolve the keys present in both.
+    ///
+    /// Keys found only in `other` are appended in the order they appear in.
+    #[inline]
+    pub fn merge(&mut self, other: Self, strategy: MergeStrategy) {
+        for (key, value) in other {
+            let Some(existing) = self.get_mut(&key) else {
+                self.insert(key, value);
+                continue;
+            };
+
+            match strategy {
+                MergeStrategy::Overwrite => *existing = value,
+
+                MergeStrategy::KeepExisting => {},
+
+                MergeStrategy::DeepMerge => {
+                    if existing.kind() == ObjectKind::Dictionary
+                        && value.kind() == ObjectKind::Dictionary
+                    {
+                        // SAFETY: we just checked that both objects are
+                        // dictionaries.
+                        unsafe {
+                            existing.as_dictionary_unchecked_mut().merge(
+                                value.into_dictionary_unchecked(),
+                                strategy,
+                            )
+                        }
+                    } else {
+                        *existing = value;
+                    }
+                },
+            }
+        }
+    }
+
     /// Creates a new, empty `Dictionary`.
     #[inline]
     pub fn new() -> Self {
@@ -477,6 +537,69 @@ mod tests {
         assert_eq!(None, iter.next());
     }
 
+    #[test]
+    fn merge_overwrite() {
+        let mut dict = Dictionary::from_iter([("foo", 1), ("bar", 2)]);
+        dict.merge(
+            Dictionary::from_iter([("bar", 3), ("baz", 4)]),
+            MergeStrategy::Overwrite,
+        );
+        assert_eq!(
+            Dictionary::from_iter([("foo", 1), ("bar", 3), ("baz", 4)]),
+            dict
+        );
+    }
+
+    #[test]
+    fn merge_keep_existing() {
+        let mut dict = Dictionary::from_iter([("foo", 1), ("bar", 2)]);
+        dict.merge(
+            Dictionary::from_iter([("bar", 3), ("baz", 4)]),
+            MergeStrategy::KeepExisting,
+        );
+        assert_eq!(
+            Dictionary::from_iter([("foo", 1), ("bar", 2), ("baz", 4)]),
+            dict
+        );
+    }
+
+    #[test]
+    fn merge_deep() {
+        let mut dict = Dictionary::from_iter([
+            ("foo", Object::from(Dictionary::from_iter([("a", 1), ("b", 2)]))),
+            ("bar", Object::from(Dictionary::from_iter([("c", 3)]))),
+            ("baz", Object::from(5)),
+        ]);
+
+        dict.merge(
+            Dictionary::from_iter([
+                (
+                    "foo",
+                    Object::from(Dictionary::from_iter([("b", 3), ("c", 4)])),
+                ),
+                ("bar", Object::from(6)),
+                ("baz", Object::from(Dictionary::from_iter([("d", 7)]))),
+            ]),
+            MergeStrategy::DeepMerge,
+        );
+
+        assert_eq!(
+            Dictionary::from_iter([
+                (
+                    "foo",
+                    Object::from(Dictionary::from_iter([
+                        ("a", 1),
+                        ("b", 3),
+                        ("c", 4)
+                    ]))
+                ),
+                ("bar", Object::from(6)),
+                ("baz", Object::from(Dictionary::from_iter([("d", 7)]))),
+            ]),
+            dict
+        );
+    }
+
     #[test]
     fn drop_iter_halfway() {
         let dict = Dictionary::from_iter([
@@ -10,6 +10,8 @@ mod error;
 mod function;
 mod kvec;
 mod macros;
+#[cfg(feature = "serde_json")]
+mod metadata;
 mod non_owning;
 mod object;
 #[cfg(feature = "serde")]
@@ -20,9 +22,11 @@ mod string_builder;
 
 pub use arena::{Arena, arena, arena_init};
 pub use array::{Array, ArrayFromTupleError};
-pub use dictionary::{Dictionary, KeyValuePair};
+pub use dictionary::{Dictionary, KeyValuePair, MergeStrategy};
 pub use error::Error;
 pub use function::Function;
+#[cfg(feature = "serde_json")]
+pub use metadata::Metadata;
 pub use non_owning::NonOwning;
 pub use object::{Object, ObjectKind};
 pub use str::NvimStr;
@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use luajit::{self as lua, Poppable, Pushable, ffi::State};
+use serde::{Deserialize, Serialize};
+
+use crate::conversion::{self, FromObject, ToObject};
+use crate::serde::{Deserializer, Serializer};
+use crate::{Object, ObjectKind};
+
+/// Arbitrary JSON data attached to a Neovim object, e.g. the `user_data`
+/// field of a diagnostic or a buffer variable.
+///
+/// The map is converted to and from a Lua table (or a Neovim
+/// [`Dictionary`](crate::Dictionary)) via [Serde](crate::serde). Since Lua
+/// doesn't distinguish between empty arrays and empty maps, nested empty
+/// objects are read back as empty arrays.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Metadata(pub HashMap<String, serde_json::Value>);
+
+impl From<HashMap<String, serde_json::Value>> for Metadata {
+    #[inline]
+    fn from(map: HashMap<String, serde_json::Value>) -> Self {
+        Self(map)
+    }
+}
+
+impl ToObject for Metadata {
+    #[inline]
+    fn to_object(self) -> Result<Object, conversion::Error> {
+        self.0.serialize(Serializer::new()).map_err(Into::into)
+    }
+}
+
+impl FromObject for Metadata {
+    #[inline]
+    fn from_object(obj: Object) -> Result<Self, conversion::Error> {
+        // An empty Lua table is indistinguishable from an empty array.
+        if obj.kind() == ObjectKind::Array
+            && unsafe { obj.as_array_unchecked() }.is_empty()
+        {
+            return Ok(Self::default());
+        }
+
+        HashMap::deserialize(Deserializer::new(obj))
+            .map(Self)
+            .map_err(Into::into)
+    }
+}
+
+impl Pushable for Metadata {
+    unsafe fn push(self, lstate: *mut State) -> core::ffi::c_int {
+        match self.to_object() {
+            Ok(obj) => obj.push(lstate),
+            Err(err) => lua::utils::push_error(&err, lstate),
+        }
+    }
+}
+
+impl Poppable for Metadata {
+    unsafe fn pop(lstate: *mut State) -> Result<Self, lua::Error> {
+        let obj = Object::pop(lstate)?;
+        Self::from_object(obj)
+            .map_err(lua::Error::pop_error_from_err::<Self, _>)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn metadata_roundtrip() {
+        let map = HashMap::from([
+            ("source".to_owned(), json!("lsp")),
+            ("code".to_owned(), json!(42)),
+            ("tags".to_owned(), json!(["a", "b"])),
+            ("range".to_owned(), json!({ "start": 1, "end": true })),
+        ]);
+
+        let obj = Metadata(map.clone()).to_object().unwrap();
+        assert_eq!(Metadata::from_object(obj).unwrap(), Metadata(map));
+    }
+
+    #[test]
+    fn metadata_from_empty_array() {
+        let obj = Object::from(crate::Array::new());
+        assert_eq!(Metadata::from_object(obj).unwrap(), Metadata::default());
+    }
+}
@@ -447,6 +447,26 @@ mod tests {
         assert_eq!(Ok(Object::from(map.clone())), d(map));
     }
 
+    #[test]
+    fn deserialize_struct_optional_fields() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Foo {
+            foo: Option<u32>,
+            bar: Option<u32>,
+            baz: Option<u32>,
+        }
+
+        let foo = Dictionary::from_iter([
+            ("foo", Object::from(42)),
+            ("bar", Object::nil()),
+        ]);
+
+        assert_eq!(
+            Foo::deserialize(Deserializer::new(foo.into())),
+            Ok(Foo { foo: Some(42), bar: None, baz: None }),
+        );
+    }
+
     #[test]
     fn deserialize_struct_unknown_field() {
         #[allow(dead_code)]
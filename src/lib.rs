@@ -3,6 +3,10 @@
 //! This library provides safe bindings to the API exposed by the [Neovim] text
 //! editor.
 //!
+//! Neovim 0.10 and later are supported. The targeted version is selected by
+//! enabling one of the `neovim-0-10`, `neovim-0-11` or `neovim-nightly`
+//! features.
+//!
 //! [Neovim]: https://neovim.io
 
 #![doc(html_root_url = "https://docs.rs/nvim_oxi/latest")]
@@ -41,6 +45,70 @@ pub mod lua {
     //! [LuaJIT]: https://luajit.org/
     #[doc(inline)]
     pub use luajit::*;
+
+    /// Evaluates a single Lua expression, popping its value as an `R`.
+    ///
+    /// This is a shorthand for [`api::exec_lua`](crate::api::exec_lua) with
+    /// `code` wrapped in `return (..)`, so `code` has to be an expression and
+    /// not a block of statements. If the expression evaluates to multiple
+    /// values, only the first one is kept.
+    ///
+    /// The wrapping doesn't add any lines before `code`, so the line numbers
+    /// in error messages match the ones in `code`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let two = nvim_oxi::lua::eval::<i64>("1 + 1")?;
+    /// ```
+    pub fn eval<R: Poppable>(code: &str) -> Result<R, crate::api::Error> {
+        let chunk = format!("return ({code}\n)");
+        crate::api::exec_lua(&chunk, ()).map_err(|err| match err {
+            crate::api::Error::LuaSyntaxError(msg) => {
+                crate::api::Error::LuaSyntaxError(format!(
+                    "{msg} (while evaluating `{code}` as an expression)"
+                ))
+            },
+            other => other,
+        })
+    }
+
+    /// Calls Lua's `require` function to load the given module, popping the
+    /// value it returns as an `R`.
+    ///
+    /// Returns [`LuaModuleNotFound`](crate::api::Error::LuaModuleNotFound)
+    /// if the module can't be found. Errors raised while loading the module,
+    /// including the ones caused by the module failing to find another
+    /// module, are returned as [`Lua`](crate::api::Error::Lua) errors.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let job = nvim_oxi::lua::require::<nvim_oxi::Object>("plenary.job")?;
+    /// ```
+    pub fn require<R: Poppable>(module: &str) -> Result<R, crate::api::Error> {
+        unsafe {
+            with_state(|lstate| {
+                ffi::lua_getglobal(lstate, luajit::macros::cstr!("require"));
+                module.to_owned().push(lstate);
+
+                if ffi::lua_pcall(lstate, 1, 1, 0) == ffi::LUA_OK {
+                    return R::pop(lstate).map_err(Into::into);
+                }
+
+                let msg = utils::pop_error_message(lstate);
+                let not_found = format!("module '{module}' not found");
+
+                if msg.starts_with(&not_found) {
+                    Err(crate::api::Error::LuaModuleNotFound(
+                        module.to_owned(),
+                    ))
+                } else {
+                    Err(Error::RuntimeError(msg).into())
+                }
+            })
+        }
+    }
 }
 
 #[cfg(feature = "mlua")]
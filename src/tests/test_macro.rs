@@ -295,11 +295,11 @@ fn exit(result: &TestResult) {
     }
 }
 
-fn run_nvim_command(
+/// Returns the path to the library built by [`build()`](super::build) for
+/// the test crate at `manifest_path`.
+pub fn library_path(
     manifest_path: &str,
-    plugin_name: &str,
-    extra_cmd: Option<&str>,
-) -> Result<Command, ExpandedTestError> {
+) -> Result<Utf8PathBuf, ExpandedTestError> {
     let manifest = super::build::CargoManifest::from_path(manifest_path)
         .map_err(ExpandedTestError::CouldntReadManifest)?;
 
@@ -312,6 +312,16 @@ fn run_nvim_command(
         return Err(ExpandedTestError::LibraryNotFound(library_path));
     }
 
+    Ok(library_path)
+}
+
+fn run_nvim_command(
+    manifest_path: &str,
+    plugin_name: &str,
+    extra_cmd: Option<&str>,
+) -> Result<Command, ExpandedTestError> {
+    let library_path = library_path(manifest_path)?;
+
     let load_library = format!(
         "lua local f = package.loadlib([[{library_path}]], \
          'luaopen_{plugin_name}'); f()",
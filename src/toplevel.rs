@@ -1,6 +1,8 @@
 use std::error::Error as StdError;
+use std::panic::{self, AssertUnwindSafe};
 
 use luajit::{self as lua, ffi::*, macros::cstr};
+use thiserror::Error as ThisError;
 use types::Function;
 
 use crate::IntoResult;
@@ -10,6 +12,10 @@ use crate::IntoResult;
 /// Schedules a callback to be invoked soon by the main event-loop. Useful to
 /// avoid [`textlock`][2] or other temporary restrictions.
 ///
+/// The callback is only called once, after which it's released. If it
+/// returns an error or panics, the error or the panic message is reported by
+/// Neovim instead of unwinding into its event loop.
+///
 /// [1]: https://neovim.io/doc/user/lua.html#vim.schedule()
 /// [2]: https://neovim.io/doc/user/eval.html#textlock
 pub fn schedule<F, R>(fun: F)
@@ -28,9 +34,25 @@ where
             lua_getglobal(lstate, cstr!("vim"));
             lua_getfield(lstate, -1, cstr!("schedule"));
 
+            // Catch any panic in the callback, which would otherwise unwind
+            // into Neovim's event loop, and report it like an error.
+            let fun = move |args| {
+                let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                    fun(args).into_result()
+                }))
+                .map_err(|payload| {
+                    ScheduleError::Panic(panic_message(&*payload))
+                })
+                .and_then(|res| res.map_err(ScheduleError::Callback));
+
+                if let Err(err) = res {
+                    crate::api::err_writeln(&err.to_string());
+                }
+            };
+
             // Store the function in the registry and put a reference to it on
             // the stack.
-            let fun = Function::from_fn_once(fun);
+            let fun = Function::<(), ()>::from_fn_once(fun);
             lua_rawgeti(lstate, LUA_REGISTRYINDEX, fun.lua_ref());
 
             lua_call(lstate, 1, 0);
@@ -41,3 +63,23 @@ where
         })
     };
 }
+
+/// The error returned by a callback passed to [`schedule`].
+#[derive(Debug, ThisError)]
+enum ScheduleError<E> {
+    #[error(transparent)]
+    Callback(E),
+
+    #[error("scheduled callback panicked: {0}")]
+    Panic(String),
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        (*msg).to_owned()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
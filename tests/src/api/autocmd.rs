@@ -110,6 +110,40 @@ fn exec_autocmds() {
     assert_eq!(1, *i.try_borrow().unwrap());
 }
 
+#[nvim_oxi::test]
+fn exec_autocmds_user_data() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let data = Rc::new(RefCell::new(None));
+
+    let cloned = Rc::clone(&data);
+
+    let opts = CreateAutocmdOpts::builder()
+        .callback(move |args: api::types::AutocmdCallbackArgs| {
+            *cloned.borrow_mut() = Some(args.data);
+            Ok::<_, nvim_oxi::Error>(true)
+        })
+        .patterns(["MyPluginReady"])
+        .build();
+
+    let id = api::create_autocmd(["User"], &opts);
+    assert!(id.is_ok(), "{id:?}");
+
+    let opts =
+        ExecAutocmdsOpts::builder().patterns("MyPluginReady").data(42).build();
+
+    let res = api::exec_autocmds(["User"], &opts);
+    assert_eq!(Ok(()), res);
+    assert_eq!(Some(42.into()), data.take());
+}
+
+#[nvim_oxi::test]
+fn exec_autocmds_invalid_event() {
+    let res = api::exec_autocmds(["NotAnEvent"], &Default::default());
+    assert!(res.is_err(), "{res:?}");
+}
+
 #[nvim_oxi::test]
 fn get_autocmds() {
     let autocmds =
@@ -145,5 +179,128 @@ fn set_exec_del_autocmd() {
         api::exec_autocmds(["BufAdd"], &ExecAutocmdsOpts::default())
     );
 
+    assert_eq!(Ok(()), api::del_autocmd(id.forget()));
+}
+
+#[nvim_oxi::test]
+fn autocmd_handle_drop() {
+    let opts = CreateAutocmdOpts::builder()
+        .command("echo 'hi'")
+        .patterns(["OxiHandleDrop"])
+        .build();
+
+    let exists = |id: u32| {
+        let opts = GetAutocmdsOpts::builder()
+            .events(["User"])
+            .patterns(["OxiHandleDrop"])
+            .build();
+        api::get_autocmds(&opts).unwrap().any(|infos| infos.id == Some(id))
+    };
+
+    let handle = api::create_autocmd(["User"], &opts).unwrap();
+    let id = handle.id();
+    assert!(exists(id));
+    drop(handle);
+    assert!(!exists(id));
+
+    let id = api::create_autocmd(["User"], &opts).unwrap().forget();
+    assert!(exists(id));
     assert_eq!(Ok(()), api::del_autocmd(id));
 }
+
+#[nvim_oxi::test]
+fn event_emitter() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use nvim_oxi::conversion::{self, FromObject, ToObject};
+    use nvim_oxi::{Dictionary, Object};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Progress {
+        file: String,
+        percent: i64,
+    }
+
+    impl ToObject for Progress {
+        fn to_object(self) -> Result<Object, conversion::Error> {
+            let dict = Dictionary::from_iter([
+                ("file", Object::from(self.file)),
+                ("percent", Object::from(self.percent)),
+            ]);
+            Ok(dict.into())
+        }
+    }
+
+    impl FromObject for Progress {
+        fn from_object(obj: Object) -> Result<Self, conversion::Error> {
+            let dict = Dictionary::from_object(obj)?;
+            let get = |key| dict.get(key).cloned().unwrap_or_default();
+            Ok(Self {
+                file: String::from_object(get("file"))?,
+                percent: i64::from_object(get("percent"))?,
+            })
+        }
+    }
+
+    let emitter = api::EventEmitter::<Progress>::new("OxiProgress");
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let subscription = emitter
+        .subscribe({
+            let received = received.clone();
+            move |progress| received.borrow_mut().push(progress)
+        })
+        .unwrap();
+
+    let progress = Progress { file: "main.rs".to_owned(), percent: 42 };
+    assert_eq!(emitter.emit(progress.clone()), Ok(()));
+    assert_eq!(*received.borrow(), std::slice::from_ref(&progress));
+
+    drop(subscription);
+    assert_eq!(emitter.emit(progress), Ok(()));
+    assert_eq!(received.borrow().len(), 1);
+}
+
+#[nvim_oxi::test]
+fn create_autocmd_specific_buffer() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use api::types::AutocmdPattern;
+
+    assert_eq!(AutocmdPattern::from("<buffer>"), AutocmdPattern::Buffer);
+    assert_eq!(
+        AutocmdPattern::from("<buffer=42>"),
+        AutocmdPattern::SpecificBuffer(42.into())
+    );
+    assert_eq!(
+        AutocmdPattern::from("*.rs"),
+        AutocmdPattern::Glob("*.rs".to_owned())
+    );
+
+    let target = api::create_buf(true, false).unwrap();
+    let other = api::create_buf(true, false).unwrap();
+
+    let fired = Rc::new(RefCell::new(Vec::new()));
+
+    let cloned = Rc::clone(&fired);
+
+    let opts = CreateAutocmdOpts::builder()
+        .callback(move |args: api::types::AutocmdCallbackArgs| {
+            cloned.borrow_mut().push(args.buffer);
+            Ok::<_, nvim_oxi::Error>(false)
+        })
+        .patterns([AutocmdPattern::SpecificBuffer(target.clone())])
+        .build();
+
+    let id = api::create_autocmd(["BufWinEnter"], &opts);
+    assert!(id.is_ok(), "{id:?}");
+
+    for buf in [&other, &target, &other] {
+        let opts = ExecAutocmdsOpts::builder().buffer(buf.clone()).build();
+        api::exec_autocmds(["BufWinEnter"], &opts).unwrap();
+    }
+
+    assert_eq!(*fired.borrow(), [target]);
+}
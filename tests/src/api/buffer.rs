@@ -1,9 +1,10 @@
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::ops;
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use all_asserts::*;
-use nvim_oxi::api::{self, Buffer, opts::*, types::*};
+use nvim_oxi::api::{self, Buffer, Window, opts::*, types::*};
 
 #[nvim_oxi::test]
 fn buf_attach() {
@@ -54,6 +55,67 @@ fn buf_attach_on_bytes() -> Result<(), api::Error> {
     Ok(())
 }
 
+#[nvim_oxi::test]
+fn buf_attach_send_buffer() -> Result<(), api::Error> {
+    let mut buffer = api::create_buf(true, false)?;
+    buffer.set_lines(.., true, ["first", "second", "third"])?;
+
+    let events = Rc::new(RefCell::new(Vec::new()));
+
+    let opts = BufAttachOpts::on_lines({
+        let events = events.clone();
+        move |event| events.borrow_mut().push(event)
+    });
+
+    buffer.attach(true, &opts)?;
+
+    {
+        let events = events.borrow();
+        assert_eq!(events.len(), 1);
+        let initial = &events[0];
+        assert_eq!(initial.buffer, buffer);
+        assert_eq!(initial.changedtick, buffer.get_changedtick()?);
+        assert_eq!(initial.first_line, 0);
+        assert_eq!(initial.last_line, 0);
+        assert_eq!(initial.new_last_line, 3);
+    }
+
+    buffer.set_lines(1..2, true, ["changed"])?;
+
+    let events = events.borrow();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[1].first_line, 1);
+    assert_eq!(events[1].last_line, 2);
+    assert_eq!(events[1].new_last_line, 2);
+
+    Ok(())
+}
+
+#[nvim_oxi::test]
+fn buf_attach_send_buffer_detach() -> Result<(), api::Error> {
+    let mut buffer = api::create_buf(true, false)?;
+
+    let calls = Rc::new(Cell::new(0));
+
+    let opts = BufAttachOpts::builder()
+        .on_lines({
+            let calls = calls.clone();
+            move |_args: OnLinesArgs| {
+                calls.set(calls.get() + 1);
+                true
+            }
+        })
+        .build();
+
+    buffer.attach(true, &opts)?;
+    assert_eq!(calls.get(), 1);
+
+    buffer.set_lines(.., true, ["changed"])?;
+    assert_eq!(calls.get(), 1);
+
+    Ok(())
+}
+
 #[nvim_oxi::test]
 fn buf_call_nil() {
     let buf = Buffer::current();
@@ -68,6 +130,32 @@ fn buf_call_int() {
     assert_eq!(Ok(42), res);
 }
 
+#[nvim_oxi::test]
+fn buf_call_context_nested() {
+    let win = Window::current();
+    let hidden = api::create_buf(false, true).unwrap();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let res = win.call({
+        let hidden = hidden.clone();
+        let seen = seen.clone();
+        move |()| {
+            hidden.call(move |()| {
+                seen.borrow_mut().push((
+                    api::CallContext::depth(),
+                    api::CallContext::current_buf(),
+                    api::CallContext::current_win(),
+                ));
+            })
+        }
+    });
+
+    assert_eq!(res, Ok(()));
+    assert_eq!(*seen.borrow(), [(2, Some(hidden), Some(win))]);
+    assert_eq!(api::CallContext::depth(), 0);
+    assert_eq!(api::CallContext::current_buf(), None);
+}
+
 #[nvim_oxi::test]
 fn buf_create_del_user_command() {
     let mut buf = Buffer::current();
@@ -150,6 +238,33 @@ fn buf_new_delete() {
     assert_eq!(Ok(()), buf.delete(&Default::default()));
 }
 
+#[nvim_oxi::test]
+fn buf_delete_force_unload() {
+    let mut buf = api::create_buf(true, false).unwrap();
+    buf.set_lines(.., true, ["foo"]).unwrap();
+    let opts = OptionOpts::builder().buf(buf.clone()).build();
+    api::set_option_value("modified", false, &opts).unwrap();
+
+    let opts = BufDeleteOpts::builder().unload(true).build();
+    assert_eq!(Ok(()), buf.clone().delete(&opts));
+    assert!(buf.is_valid());
+    assert!(!buf.is_loaded());
+
+    assert_eq!(Ok(()), buf.clone().delete(&Default::default()));
+    assert!(!buf.is_valid());
+
+    let mut buf = api::create_buf(true, false).unwrap();
+    buf.set_lines(.., true, ["foo"]).unwrap();
+
+    let res = buf.clone().delete(&Default::default());
+    assert!(res.is_err(), "{res:?}");
+    assert!(buf.is_valid());
+
+    let opts = BufDeleteOpts::builder().force(true).build();
+    assert_eq!(Ok(()), buf.clone().delete(&opts));
+    assert!(!buf.is_valid());
+}
+
 #[nvim_oxi::test]
 fn buf_set_get_del_keymap() {
     let mut buf = Buffer::current();
@@ -287,15 +402,53 @@ fn buf_set_get_del_var() {
     assert_eq!(Ok(()), buf.del_var("foo"));
 }
 
+#[nvim_oxi::test]
+fn buf_set_name_already_exists() {
+    let mut first = api::create_buf(true, false).unwrap();
+    first.set_name("MyPlugin://tree").unwrap();
+
+    let mut second = api::create_buf(true, false).unwrap();
+    let res = second.set_name("MyPlugin://tree");
+    assert!(res.is_err(), "{res:?}");
+    assert_eq!(second.get_name().unwrap(), PathBuf::new());
+}
+
 #[nvim_oxi::test]
 fn buf_set_get_name() {
     let mut buf = api::create_buf(true, false).unwrap();
-    assert_eq!(buf.get_name().unwrap(), "");
+    assert_eq!(buf.get_name().unwrap(), PathBuf::new());
     buf.set_name("foo").unwrap();
-    assert!(buf.get_name().unwrap().to_string_lossy().ends_with("foo"));
+    assert!(buf.get_name().unwrap().ends_with("foo"));
     buf.set_name("").unwrap();
 }
 
+#[nvim_oxi::test]
+fn buf_set_name_idempotent_clear_name() {
+    let mut buf = api::create_buf(true, false).unwrap();
+
+    let path = std::env::temp_dir().join("nvim-oxi-ünïcødé-名前.txt");
+    buf.set_name(&path).unwrap();
+    assert_eq!(buf.get_name().unwrap(), path);
+
+    // Setting the same name again doesn't fire `BufFilePre`.
+    let opts = CreateAutocmdOpts::builder()
+        .buffer(buf.clone())
+        .command("let b:renamed = 1")
+        .build();
+    let _autocmd = api::create_autocmd(["BufFilePre"], &opts).unwrap();
+    buf.set_name(&path).unwrap();
+    assert!(buf.get_var::<i32>("renamed").is_err());
+
+    // Relative names are compared against the full path Neovim gives them.
+    buf.set_name("nvim-oxi-relative.txt").unwrap();
+    buf.del_var("renamed").unwrap();
+    buf.set_name("nvim-oxi-relative.txt").unwrap();
+    assert!(buf.get_var::<i32>("renamed").is_err());
+
+    buf.clear_name().unwrap();
+    assert_eq!(buf.get_name().unwrap(), PathBuf::new());
+}
+
 #[nvim_oxi::test]
 fn buf_set_get_option() {
     let mut buf = Buffer::current();
@@ -307,6 +460,51 @@ fn buf_set_get_option() {
     assert!(!buf.get_option::<bool>("modified").unwrap());
 }
 
+#[cfg(feature = "ropey")]
+#[nvim_oxi::test]
+fn buf_set_get_rope() {
+    let mut buf = Buffer::current();
+
+    let rope = ropey::Rope::from_str("foo\nbar\n\nbaz");
+    assert_eq!(Ok(()), buf.set_from_rope(&rope));
+    assert_eq!(
+        vec!["foo", "bar", "", "baz"],
+        buf.get_lines(.., true)
+            .unwrap()
+            .map(|s| s.to_string_lossy().into())
+            .collect::<Vec<String>>()
+    );
+    assert_eq!(Ok(rope), buf.get_as_rope());
+
+    // Takes more than one batch, and replaces all the previous lines.
+    let text = (0..10_000).map(|n| n.to_string()).collect::<Vec<_>>();
+    let rope = ropey::Rope::from_str(&text.join("\n"));
+    assert_eq!(Ok(()), buf.set_from_rope(&rope));
+    assert_eq!(Ok(10_000), buf.line_count());
+    assert_eq!(Ok(rope), buf.get_as_rope());
+}
+
+#[nvim_oxi::test]
+fn buf_set_typed_option() {
+    let mut buf = api::create_buf(true, false).unwrap();
+    let opts = OptionOpts::builder().buf(buf.clone()).build();
+
+    buf.set_typed_option(BufOption::Shiftwidth(3)).unwrap();
+    assert_eq!(api::get_option_value::<i64>("shiftwidth", &opts), Ok(3));
+
+    buf.set_typed_option(BufOption::Filetype("rust".into())).unwrap();
+    assert_eq!(
+        api::get_option_value::<String>("filetype", &opts),
+        Ok("rust".into())
+    );
+
+    // The value in the current buffer is left untouched.
+    assert_ne!(
+        api::get_option_value::<i64>("shiftwidth", &Default::default()),
+        Ok(3)
+    );
+}
+
 #[cfg_attr(target_os = "windows", ignore = "Windows' paths are dumb")]
 #[nvim_oxi::test]
 fn buf_terminal_name() {
@@ -317,7 +515,7 @@ fn buf_terminal_name() {
     let term_name_lua =
         api::exec("lua =vim.api.nvim_buf_get_name(0)", true).unwrap().unwrap();
 
-    assert_eq!(term_name_oxi, term_name_lua.trim_matches('"'));
+    assert_eq!(term_name_oxi, PathBuf::from(term_name_lua.trim_matches('"')));
 }
 
 enum Range<T> {
@@ -405,3 +603,73 @@ impl<T> ops::RangeBounds<T> for Range<T> {
         }
     }
 }
+
+#[nvim_oxi::test]
+fn buf_watch_var() {
+    use std::cell::RefCell;
+
+    let mut buf = Buffer::current();
+
+    let changes = Rc::new(RefCell::new(Vec::new()));
+
+    let token = {
+        let changes = Rc::clone(&changes);
+        buf.watch_var::<i64, _>("foo", move |old, new| {
+            changes.borrow_mut().push((old, new))
+        })
+        .unwrap()
+    };
+
+    // Set the variable from an autocommand. The watch is only notified on
+    // the next edit.
+    let opts = CreateAutocmdOpts::builder()
+        .command("let b:foo = 42")
+        .buffer(buf.clone())
+        .once(true)
+        .build();
+    let _autocmd = api::create_autocmd(["TextChanged"], &opts).unwrap();
+    api::exec_autocmds(["TextChanged"], &Default::default()).unwrap();
+
+    buf.set_lines(.., true, ["foo"]).unwrap();
+    assert_eq!(*changes.borrow(), [(None, Some(42))]);
+
+    // Editing the buffer without changing the variable doesn't fire.
+    buf.set_lines(.., true, ["bar"]).unwrap();
+    assert_eq!(changes.borrow().len(), 1);
+
+    buf.set_var("foo", 7).unwrap();
+    buf.set_lines(.., true, ["baz"]).unwrap();
+    assert_eq!(*changes.borrow(), [(None, Some(42)), (Some(42), Some(7))]);
+
+    drop(token);
+
+    buf.del_var("foo").unwrap();
+    buf.set_lines(.., true, ["foo"]).unwrap();
+    assert_eq!(changes.borrow().len(), 2);
+}
+
+#[nvim_oxi::test]
+fn buf_iter_lines() {
+    let mut buf = Buffer::current();
+    let lines = (0..10_000).map(|i| i.to_string()).collect::<Vec<_>>();
+    buf.set_lines(.., true, lines.iter().map(String::as_str)).unwrap();
+
+    let opts = BufIterOpts::builder().batch_size(100).build();
+    let mut iter = buf.iter_lines(.., &opts).unwrap();
+    assert_eq!(iter.len(), 10_000);
+
+    let first = iter.next().unwrap().unwrap();
+    assert_eq!(first, "0");
+    assert_eq!(iter.len(), 9_999);
+
+    let rest = iter.map(Result::unwrap).collect::<Vec<_>>();
+    assert_eq!(rest.len(), 9_999);
+    assert_eq!(rest.last().unwrap(), "9999");
+
+    let iter = buf.iter_lines(10..20, &opts).unwrap();
+    assert_eq!(iter.len(), 10);
+
+    let iter = buf.iter_lines(9_990..=usize::MAX, &opts).unwrap();
+    assert_eq!(iter.len(), 10);
+    assert_eq!(iter.map(Result::unwrap).count(), 10);
+}
@@ -1,4 +1,63 @@
-use nvim_oxi::api::{self, opts::*, types::*};
+use nvim_oxi::api::{
+    self,
+    Buffer,
+    CommandBuilder,
+    LineRange,
+    opts::*,
+    types::*,
+};
+
+#[nvim_oxi::test]
+fn command_builder_substitute() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["a/a", "a/a", "a/a"]).unwrap();
+
+    CommandBuilder::new("substitute")
+        .range(LineRange::Lines(2, 3))
+        .pattern("a/")
+        .replacement("b/")
+        .flags("g")
+        .exec(&Default::default())
+        .unwrap();
+
+    let lines = buf
+        .get_lines(.., true)
+        .unwrap()
+        .map(|line| line.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(lines, ["a/a", "b/a", "b/a"]);
+
+    CommandBuilder::new("substitute")
+        .range(LineRange::WholeBuffer)
+        .pattern("a")
+        .replacement("c")
+        .flags("g")
+        .exec(&Default::default())
+        .unwrap();
+
+    let lines = buf
+        .get_lines(.., true)
+        .unwrap()
+        .map(|line| line.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(lines, ["c/c", "b/c", "b/c"]);
+}
+
+#[nvim_oxi::test]
+fn command_builder_invalid() {
+    let res = CommandBuilder::new("normal")
+        .range(LineRange::CurrentLine)
+        .count(2)
+        .arg("x")
+        .build();
+    assert!(res.is_err());
+
+    let res = CommandBuilder::new("substitute").replacement("b").build();
+    assert!(res.is_err());
+
+    let res = CommandBuilder::new("").build();
+    assert!(res.is_err());
+}
 
 #[nvim_oxi::test]
 fn command_nargs_0() {
@@ -27,3 +86,13 @@ fn regression_1() {
         .build();
     api::create_user_command("Echo", "", &opts).unwrap();
 }
+
+#[nvim_oxi::test]
+fn command_preview() {
+    let opts = CreateCommandOpts::builder()
+        .nargs(CommandNArgs::ZeroOrOne)
+        .preview(|(_args, _ns_id, _preview_buf)| CommandPreview::Replace)
+        .build();
+    api::create_user_command("Preview", ":", &opts).unwrap();
+    assert_eq!(api::command("Preview"), Ok(()));
+}
@@ -0,0 +1,79 @@
+use nvim_oxi::api::{self, Buffer, diagnostic, opts::*, types::*};
+
+#[nvim_oxi::test]
+fn diagnostic_open_float() {
+    let opts = DiagnosticFloatOpts::builder()
+        .scope(DiagnosticScope::Buffer)
+        .header("Oxi diagnostics")
+        .source(DiagnosticSource::Never)
+        .border(WindowBorder::Rounded)
+        .build();
+
+    assert_eq!(diagnostic::open_float(None, &opts), Ok(None));
+
+    let buf = Buffer::current();
+    let ns_id = api::create_namespace("diagnostic_open_float");
+    api::exec_lua::<_, ()>(
+        "vim.diagnostic.set(..., 0, { { lnum = 0, col = 0, message = 'oops' \
+         } })",
+        (ns_id,),
+    )
+    .unwrap();
+
+    let (win, float_buf) =
+        diagnostic::open_float(Some(&buf), &opts).unwrap().unwrap();
+
+    assert!(win.is_valid());
+    assert_eq!(win.get_buf(), Ok(float_buf.clone()));
+    assert!(win.get_config().unwrap().relative.is_some());
+
+    let lines = float_buf
+        .get_lines(.., false)
+        .unwrap()
+        .map(|line| line.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.first().map(String::as_str), Some("Oxi diagnostics"));
+    assert!(lines.iter().any(|line| line.contains("oops")));
+}
+
+#[nvim_oxi::test]
+fn diagnostic_enable_disable_reset() {
+    let buf = Buffer::current();
+    let ns_id = api::create_namespace("diagnostic_enable_disable_reset");
+    api::exec_lua::<_, ()>(
+        "vim.diagnostic.set(..., 0, { { lnum = 0, col = 0, message = 'oops' \
+         } })",
+        (ns_id,),
+    )
+    .unwrap();
+
+    let is_enabled = || {
+        const IS_ENABLED: &str = r#"
+            local bufnr, ns_id = ...
+            return vim.diagnostic.is_enabled({ bufnr = bufnr, ns_id = ns_id })
+        "#;
+        api::exec_lua::<_, bool>(IS_ENABLED, (buf.clone(), ns_id)).unwrap()
+    };
+
+    let count = || {
+        api::exec_lua::<_, usize>(
+            "return #vim.diagnostic.get(...)",
+            (buf.clone(),),
+        )
+        .unwrap()
+    };
+
+    let opts = DiagnosticEnableOpts::builder().ns_id(ns_id).build();
+
+    assert!(is_enabled());
+
+    diagnostic::disable(Some(&buf), &opts).unwrap();
+    assert!(!is_enabled());
+    assert_eq!(count(), 1);
+
+    diagnostic::enable(Some(&buf), &opts).unwrap();
+    assert!(is_enabled());
+
+    diagnostic::reset(ns_id, Some(&buf)).unwrap();
+    assert_eq!(count(), 0);
+}
@@ -51,7 +51,8 @@ fn get_extmarks() {
     let extmarks = res.unwrap();
     assert_eq!(1, extmarks.len());
 
-    let (id, row, col, infos) = extmarks.into_iter().next().unwrap();
+    let Extmark { id, row, col, details: infos, .. } =
+        extmarks.into_iter().next().unwrap();
 
     assert!(infos.is_some(), "no informations were returned");
 
@@ -59,6 +60,8 @@ fn get_extmarks() {
 
     assert_eq!(extmark_id, id);
     assert_eq!((row, col), (0, 0));
+    assert_eq!(infos.conceal.as_deref(), Some("a"));
+    assert_eq!(infos.cursorline_hl_group.as_deref(), Some("Foo"));
     assert_eq!(Some(true), infos.end_right_gravity);
     assert_eq!(Some(0), infos.end_row);
     assert_eq!(infos.hl_group.unwrap(), String::from("Bar"));
@@ -143,6 +146,66 @@ fn set_decoration_provider() {
     assert!(on_end_called.get());
 }
 
+#[nvim_oxi::test]
+fn decoration_provider_on_end() {
+    let id = api::create_namespace("Foo");
+
+    let start_tick = Rc::new(Cell::new(None));
+    let end_tick = Rc::new(Cell::new(None));
+
+    let opts = DecorationProviderOpts::builder()
+        .on_start({
+            let start_tick = start_tick.clone();
+            move |(_, tick)| {
+                start_tick.set(Some(tick));
+                true
+            }
+        })
+        .on_end({
+            let end_tick = end_tick.clone();
+            move |(event, tick): (String, u32)| {
+                assert_eq!(event, "end");
+                end_tick.set(Some(tick));
+            }
+        })
+        .build();
+
+    api::set_decoration_provider(id, &opts).unwrap();
+    api::command("redraw!").expect("redraw failed");
+
+    assert!(end_tick.get().is_some());
+    assert_eq!(end_tick.get(), start_tick.get());
+}
+
+#[nvim_oxi::test]
+fn decoration_provider_captured_ns() {
+    let id = api::create_namespace("Foo");
+
+    let extmark_set = Rc::new(Cell::new(false));
+
+    let opts = DecorationProviderOpts::builder()
+        .on_line({
+            let extmark_set = extmark_set.clone();
+            move |(_, _, mut buf, row): OnLineArgs| {
+                let opts = SetExtmarkOpts::builder()
+                    .end_col(3)
+                    .hl_group("Error")
+                    .ephemeral(true)
+                    .build();
+                let res = buf.set_extmark(id, row, 0, &opts);
+                extmark_set.set(res.is_ok());
+            }
+        })
+        .build();
+
+    api::set_decoration_provider(id, &opts).unwrap();
+
+    api::Buffer::current().set_lines(0..0, true, ["foo"]).unwrap();
+    api::command("redraw!").expect("redraw failed");
+
+    assert!(extmark_set.get());
+}
+
 #[nvim_oxi::test]
 fn set_extmark_via_group_id() {
     let mut buf = Buffer::current();
@@ -164,7 +227,7 @@ fn set_extmark_via_group_id() {
 
     let opts = GetExtmarkByIdOpts::builder().details(true).build();
 
-    let Ok((_, _, Some(infos))) =
+    let Ok(Some(Extmark { details: Some(infos), .. })) =
         buf.get_extmark_by_id(ns_id, extmark_id, &opts)
     else {
         unreachable!()
@@ -213,8 +276,8 @@ fn set_get_del_extmark() {
     let got = buf.get_extmark_by_id(ns_id, extmark_id, &opts);
     assert!(got.is_ok(), "{got:?}");
 
-    let (row, col, infos) = got.unwrap();
-    assert_eq!((row, col), (0, 0));
+    let Extmark { id, row, col, details: infos, .. } = got.unwrap().unwrap();
+    assert_eq!((id, row, col), (extmark_id, 0, 0));
 
     assert!(infos.is_some(), "no informations were returned");
 
@@ -241,6 +304,9 @@ fn set_get_del_extmark() {
 
     let res = buf.del_extmark(ns_id, extmark_id);
     assert_eq!(Ok(()), res);
+
+    let got = buf.get_extmark_by_id(ns_id, extmark_id, &Default::default());
+    assert_eq!(got, Ok(None));
 }
 
 #[nvim_oxi::test]
@@ -258,7 +324,7 @@ fn virt_text_pos_inline() {
 
     let opts = GetExtmarkByIdOpts::builder().details(true).build();
 
-    let Ok((_, _, Some(infos))) =
+    let Ok(Some(Extmark { details: Some(infos), .. })) =
         buf.get_extmark_by_id(ns_id, extmark_id, &opts)
     else {
         unreachable!()
@@ -266,3 +332,143 @@ fn virt_text_pos_inline() {
 
     assert_eq!(infos.virt_text_pos, Some(ExtmarkVirtTextPosition::Inline));
 }
+
+#[nvim_oxi::test]
+fn buf_attach_highlights() {
+    let id = api::create_namespace("Foo");
+
+    let drawn = Rc::new(Cell::new(None));
+
+    let mut buf = api::Buffer::current();
+    buf.set_lines(.., true, ["a", "b", "c"]).unwrap();
+
+    let res = buf.attach_highlights(id, {
+        let drawn = drawn.clone();
+        move |buf, win, start, end| {
+            assert_eq!(*buf, api::Buffer::current());
+            assert_eq!(win as i32, api::Window::current().handle());
+            drawn.set(Some((start, end)));
+        }
+    });
+    assert_eq!(Ok(()), res);
+
+    api::command("redraw!").expect("redraw failed");
+
+    let (start, end) = drawn.get().expect("on_line wasn't called");
+    assert_eq!(start, 0);
+    assert!(end >= 2);
+}
+
+#[nvim_oxi::test]
+fn set_virtual_text() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["first", "second"]).unwrap();
+    let ns_id = api::create_namespace("Foo");
+
+    let opts = VirtTextOpts::builder()
+        .hl_mode(ExtmarkHlMode::Combine)
+        .virt_text_pos(ExtmarkVirtTextPosition::RightAlign)
+        .build();
+
+    let extmark_id =
+        buf.set_virtual_text(ns_id, 1, [("hint", "Comment")], &opts).unwrap();
+
+    let opts = GetExtmarkByIdOpts::builder().details(true).build();
+    let extmark =
+        buf.get_extmark_by_id(ns_id, extmark_id, &opts).unwrap().unwrap();
+    assert_eq!((extmark.row, extmark.col), (1, 0));
+
+    let infos = extmark.details.unwrap();
+    assert_eq!(infos.hl_mode, Some(ExtmarkHlMode::Combine));
+    assert_eq!(infos.virt_text_pos, Some(ExtmarkVirtTextPosition::RightAlign));
+    assert_eq!(
+        infos.virt_text,
+        [ExtmarkVirtTextChunk {
+            text: "hint".to_owned(),
+            hl_groups: vec!["Comment".into()],
+        }]
+    );
+
+    buf.del_extmark(ns_id, extmark_id).unwrap();
+    assert_eq!(buf.get_extmark_by_id(ns_id, extmark_id, &opts), Ok(None));
+}
+
+#[nvim_oxi::test]
+fn namespace_clears_on_drop() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["abc", "def"]).unwrap();
+
+    let count_extmarks = |buf: &Buffer, ns_id| {
+        let start = ExtmarkPosition::ByTuple((0, 0));
+        let end = ExtmarkPosition::ByTuple((1, 3));
+        buf.get_extmarks(ns_id, start, end, &Default::default())
+            .unwrap()
+            .count()
+    };
+
+    let opts = SetExtmarkOpts::default();
+
+    let namespace = api::Namespace::create("namespace_drop");
+    let ns_id = namespace.id();
+    buf.set_extmark(ns_id, 0, 0, &opts).unwrap();
+    buf.set_extmark(ns_id, 1, 1, &opts).unwrap();
+    assert_eq!(count_extmarks(&buf, ns_id), 2);
+
+    drop(namespace);
+    assert_eq!(count_extmarks(&buf, ns_id), 0);
+
+    let namespace = api::Namespace::create("namespace_forget");
+    let ns_id = namespace.id();
+    buf.set_extmark(ns_id, 0, 0, &opts).unwrap();
+    assert_eq!(namespace.forget(), ns_id);
+    assert_eq!(count_extmarks(&buf, ns_id), 1);
+}
+
+#[nvim_oxi::test]
+fn get_extmarks_details() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["abc", "def"]).unwrap();
+    let ns_id = api::create_namespace("get_extmarks_details");
+
+    let opts = SetExtmarkOpts::builder()
+        .sign_text("ab")
+        .sign_hl_group("Search")
+        .number_hl_group("LineNr")
+        .spell(true)
+        .build();
+    let first = buf.set_extmark(ns_id, 0, 1, &opts).unwrap();
+    let second = buf.set_extmark(ns_id, 1, 2, &Default::default()).unwrap();
+
+    let start = ExtmarkPosition::ByTuple((0, 0));
+    let end = ExtmarkPosition::ByTuple((1, 3));
+
+    let extmarks = buf
+        .get_extmarks(ns_id, start, end, &Default::default())
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(extmarks.len(), 2);
+    assert_eq!(
+        (extmarks[0].id, extmarks[0].row, extmarks[0].col),
+        (first, 0, 1)
+    );
+    assert_eq!(
+        (extmarks[1].id, extmarks[1].row, extmarks[1].col),
+        (second, 1, 2)
+    );
+    assert!(extmarks.iter().all(|extmark| extmark.details.is_none()));
+
+    let opts = GetExtmarksOpts::builder().details(true).build();
+    let details = buf
+        .get_extmarks(ns_id, start, end, &opts)
+        .unwrap()
+        .next()
+        .and_then(|extmark| extmark.details)
+        .unwrap();
+
+    assert_eq!(details.sign_text.as_deref(), Some("ab"));
+    assert_eq!(details.sign_hl_group.as_deref(), Some("Search"));
+    assert_eq!(details.number_hl_group.as_deref(), Some("LineNr"));
+    assert_eq!(details.spell, Some(true));
+    assert_eq!(details.line_hl_group, None);
+}
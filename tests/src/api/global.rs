@@ -1,10 +1,31 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use all_asserts::*;
 use nvim_oxi::Dictionary;
+use nvim_oxi::api::color::Color;
 use nvim_oxi::api::{self, Buffer, Window, opts::*, types::*};
 use nvim_oxi::mlua::{Error as LuaError, IntoLuaMulti, Lua, Table};
 
+#[nvim_oxi::test]
+fn api_version() {
+    let version = api::api_version().unwrap();
+
+    let nvim_version = api::exec_lua::<_, Vec<u32>>(
+        "local v = vim.version() return { v.major, v.minor, v.patch }",
+        (),
+    )
+    .unwrap();
+    assert_eq!([version.major, version.minor, version.patch], *nvim_version);
+    assert_le!(version.api_compatible, version.api_level);
+
+    if cfg!(feature = "neovim-0-11") {
+        assert_le!(11, version.minor);
+    }
+
+    assert!(core::ptr::eq(version, api::api_version().unwrap()));
+}
+
 #[nvim_oxi::test]
 fn chan_send_fail() {
     let res = api::chan_send(42, "hello there");
@@ -31,6 +52,41 @@ fn create_del_user_command() {
     assert_eq!(Ok(()), api::del_user_command("Bar"));
 }
 
+#[nvim_oxi::test]
+fn get_commands_infos() {
+    let opts = CreateCommandOpts::builder()
+        .bang(true)
+        .complete(CommandComplete::File)
+        .nargs(CommandNArgs::OneOrMore)
+        .build();
+    let res = api::create_user_command("Foo", "echo 'foo'", &opts);
+    assert_eq!(Ok(()), res);
+
+    let opts = CreateCommandOpts::builder().desc("does nothing").build();
+    let res = api::create_user_command("Bar", |_args| (), &opts);
+    assert_eq!(Ok(()), res);
+
+    let mut commands = api::get_commands(&Default::default()).unwrap();
+
+    let echo = commands.find(|cmd| cmd.name == "Foo").unwrap();
+    assert!(echo.bang);
+    assert!(echo.callback.is_none());
+    assert_eq!(Some("file"), echo.complete.as_deref());
+    assert_eq!(Some("echo 'foo'"), echo.definition.as_deref());
+    assert_eq!(CommandNArgs::OneOrMore, echo.nargs);
+
+    let mut commands = api::get_commands(&Default::default()).unwrap();
+
+    let callback = commands.find(|cmd| cmd.name == "Bar").unwrap();
+    assert!(!callback.bang);
+    assert!(callback.callback.is_some());
+    assert_eq!(Some("does nothing"), callback.definition.as_deref());
+    assert_eq!(CommandNArgs::Zero, callback.nargs);
+
+    assert_eq!(Ok(()), api::del_user_command("Foo"));
+    assert_eq!(Ok(()), api::del_user_command("Bar"));
+}
+
 #[nvim_oxi::test]
 fn echo() {
     api::echo(
@@ -78,6 +134,49 @@ fn eval_statusline_empty() {
     assert!(infos.highlights.is_empty());
 }
 
+#[nvim_oxi::test]
+fn exec_lua() {
+    let res = api::exec_lua::<_, i32>("return ... + 1", 41);
+    assert_eq!(Ok(42), res);
+
+    // Only the first value is kept.
+    let res = api::exec_lua::<_, i32>("return 1, 2", ());
+    assert_eq!(Ok(1), res);
+}
+
+#[nvim_oxi::test]
+fn error_vars() {
+    api::set_vvar("errmsg", "").unwrap();
+    api::command("silent! call OxiDoesNotExist()").unwrap();
+
+    let vars = api::error_vars().unwrap();
+    assert!(vars.errmsg.contains("E117"), "{vars:?}");
+    assert_eq!(vars.exception, "");
+}
+
+#[nvim_oxi::test]
+fn eval_lua() {
+    assert_eq!(nvim_oxi::lua::eval::<i64>("1 + 1"), Ok(2));
+
+    let res = nvim_oxi::lua::eval::<i64>("local x = 1");
+    assert!(res.is_err());
+}
+
+#[nvim_oxi::test]
+fn exec_lua_runtime_error() {
+    let res = api::exec_lua::<_, ()>("error('oops')", ());
+    assert!(matches!(
+        res,
+        Err(api::Error::Lua(nvim_oxi::lua::Error::RuntimeError(_)))
+    ));
+}
+
+#[nvim_oxi::test]
+fn exec_lua_syntax_error() {
+    let res = api::exec_lua::<_, ()>("return +", ());
+    assert!(matches!(res, Err(api::Error::LuaSyntaxError(_))));
+}
+
 #[nvim_oxi::test]
 fn feedkeys() {
     let keys = "iHllo<Esc>bi<Right>e";
@@ -92,6 +191,55 @@ fn feedkeys() {
     assert_eq!(lines, ["Hello"]);
 }
 
+#[nvim_oxi::test]
+fn feedkeys_mode() {
+    use api::types::FeedKeysMode;
+
+    let keys = api::replace_termcodes("iHello<Esc>", true, false, true);
+    let mode = FeedKeysMode::NOREMAP | FeedKeysMode::EXECUTE;
+    api::feedkeys(&keys, &nvim_oxi::String::from(mode), false);
+
+    let lines = api::Buffer::current()
+        .get_lines(0..1, true)
+        .unwrap()
+        .collect::<Vec<_>>();
+
+    assert_eq!(lines, ["Hello"]);
+
+    let flags = nvim_oxi::lua::Flags(mode);
+    let res = api::exec_lua::<_, nvim_oxi::lua::Flags<FeedKeysMode>>(
+        "return ...",
+        flags,
+    );
+    assert_eq!(Ok(flags), res);
+
+    let res = api::exec_lua::<_, nvim_oxi::lua::Flags<FeedKeysMode>>(
+        "return 128",
+        (),
+    );
+    assert!(res.is_err(), "{res:?}");
+}
+
+#[nvim_oxi::test]
+fn pop_string_strict() {
+    use nvim_oxi::lua::ToStringCoerce;
+
+    let res = api::exec_lua::<_, String>("return 'foo'", ());
+    assert_eq!(res, Ok("foo".to_owned()));
+
+    let res = api::exec_lua::<_, String>("return 42", ());
+    assert!(res.is_err(), "{res:?}");
+
+    let res = api::exec_lua::<_, String>(r#"return "\xff""#, ());
+    assert!(res.is_err(), "{res:?}");
+
+    let res = api::exec_lua::<_, ToStringCoerce>("return 42", ());
+    assert_eq!(res, Ok(ToStringCoerce("42".to_owned())));
+
+    let res = api::exec_lua::<_, ToStringCoerce>(r#"return "\xff""#, ());
+    assert_eq!(res, Ok(ToStringCoerce("\u{fffd}".to_owned())));
+}
+
 #[nvim_oxi::test]
 fn get_chan_info() {
     let res = api::get_chan_info(0);
@@ -107,12 +255,42 @@ fn get_colors() {
     assert_eq!(color, api::get_color_by_name(&name).unwrap());
 }
 
+#[nvim_oxi::test]
+fn color_to_rgb() {
+    let red = "Red".parse::<Color>().unwrap();
+    assert_eq!(red, Color::Named("Red".into()));
+    assert_eq!(red.to_rgb(), Ok((255, 0, 0)));
+
+    let missing = Color::Named("NotAColor".into());
+    assert!(missing.to_rgb().is_err());
+
+    let lua_red = api::exec_lua::<_, Color>("return 'Red'", ()).unwrap();
+    assert_eq!(lua_red, red);
+}
+
 #[nvim_oxi::test]
 fn get_context() {
     let res = api::get_context(&Default::default());
     assert!(res.is_ok());
 }
 
+#[nvim_oxi::test]
+fn get_load_context() {
+    api::command("let @a = 'foo'").unwrap();
+
+    let opts =
+        GetContextOpts::builder().types([ContextType::Registers]).build();
+    let ctx = api::get_context(&opts).unwrap();
+    assert!(!ctx.registers.is_empty());
+    assert!(ctx.global_vars.is_empty());
+
+    api::command("let @a = 'bar'").unwrap();
+    api::load_context(ctx);
+
+    let reg = api::call_function::<_, String>("getreg", ("a",)).unwrap();
+    assert_eq!("foo", reg);
+}
+
 #[nvim_oxi::test]
 fn get_highlights() {
     let (name, _) = api::get_color_map().next().unwrap();
@@ -133,6 +311,34 @@ fn get_hl() {
     assert!(infos.background.is_some());
 }
 
+#[nvim_oxi::test]
+fn get_hl_cterm_colors() {
+    let opts = SetHighlightOpts::builder()
+        .foreground(Color::Rgb(0, 0, 196))
+        .ctermfg(Color::Terminal(196))
+        .build();
+    api::set_hl(0, "OxiCtermFg", &opts).unwrap();
+
+    let opts = GetHighlightOpts::builder().name("OxiCtermFg").build();
+    let GetHlInfos::Single(infos) = api::get_hl(0, &opts).unwrap() else {
+        panic!("expected a single group")
+    };
+    assert_eq!(infos.foreground, Some(Color::Rgb(0, 0, 196)));
+    assert_eq!(infos.ctermfg, Some(Color::Terminal(196)));
+}
+
+#[nvim_oxi::test]
+fn get_hl_groups() {
+    let groups = api::get_hl_groups(0).unwrap();
+    assert!(groups.iter().any(|name| name == "Normal"));
+    assert!(groups.iter().any(|name| name == "Error"));
+
+    let ns_id = api::create_namespace("oxi_get_hl_groups");
+    let opts = SetHighlightOpts::builder().bold(true).build();
+    api::set_hl(ns_id, "OxiGroup", &opts).unwrap();
+    assert_eq!(api::get_hl_groups(ns_id).unwrap(), ["OxiGroup"]);
+}
+
 #[nvim_oxi::test]
 fn get_mode() {
     let GotMode { blocking, mode, .. } = api::get_mode();
@@ -154,38 +360,149 @@ fn get_option_info() {
     assert!(api::get_option_info("number").is_ok());
 }
 
+#[nvim_oxi::test]
+fn get_process_info() {
+    let pid = std::process::id();
+    let info = api::get_process_info(pid).unwrap().unwrap();
+    assert_eq!(pid, info.pid);
+    assert_eq!(None, info.uid);
+
+    assert_eq!(Ok(None), api::get_process_info(i32::MAX as u32));
+}
+
 #[nvim_oxi::test]
 fn get_runtime_file() {
     assert!(api::get_runtime_file("*", true).unwrap().next().is_some());
 }
 
+#[nvim_oxi::test]
+fn get_runtime_file_glob() {
+    let all =
+        api::get_runtime_file("lua/*.lua", true).unwrap().collect::<Vec<_>>();
+    assert_lt!(1, all.len());
+    assert!(all.iter().all(|path| path.extension().unwrap() == "lua"));
+
+    let first =
+        api::get_runtime_file("lua/*.lua", false).unwrap().collect::<Vec<_>>();
+    assert_eq!(&all[..1], &first[..]);
+
+    let nested = api::get_runtime_file("lua/**/*.lua", true).unwrap();
+    assert_lt!(all.len(), nested.count());
+}
+
+#[nvim_oxi::test]
+fn grow_stack_overflow() {
+    use nvim_oxi::{Function, lua};
+
+    let double = Function::<usize, usize>::from_fn(|n| n * 2);
+
+    unsafe {
+        lua::with_state(|lstate| {
+            let height = lua::ffi::lua_gettop(lstate);
+
+            // LuaJIT doesn't let a C function use more than 8000 slots, so
+            // this leaves too little room to call a function.
+            assert_eq!(lua::utils::grow_stack(lstate, 7_990), Ok(()));
+            assert!(lua::utils::grow_stack(lstate, 8_010).is_err());
+            assert!(double.call(21).is_err());
+
+            lua::ffi::lua_settop(lstate, height);
+        })
+    };
+
+    assert_eq!(double.call(21), Ok(42));
+
+    let values = (0..10_000).map(|i| vec![i]).collect::<Vec<_>>();
+    let len = api::exec_lua::<_, usize>("return #(...)", values).unwrap();
+    assert_eq!(len, 10_000);
+}
+
+#[nvim_oxi::test]
+fn hash_set_push_pop() {
+    let set = api::exec_lua::<_, HashSet<String>>(
+        r#"return { "foo", "bar", "foo", "baz", "bar" }"#,
+        (),
+    )
+    .unwrap();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains("foo"));
+
+    let len = api::exec_lua::<_, usize>("return #(...)", set).unwrap();
+    assert_eq!(len, 3);
+}
+
 #[nvim_oxi::test]
 #[cfg_attr(feature = "neovim-0-11", ignore = "deprecated in 0.11")]
 fn hl_foreground() {
     let foreground = "#FF0000";
     let opts = SetHighlightOpts::builder()
-        .foreground(foreground)
+        .foreground(foreground.parse::<Color>().unwrap())
         .strikethrough(true)
         .bold(true)
         .build();
     api::set_hl(0, "Header", &opts).unwrap();
 
     let infos = api::get_hl_by_name("Header", true).unwrap();
-    assert_eq!(infos.foreground, Some(hex_to_dec(foreground)));
+    assert_eq!(infos.foreground, Some(hex_to_dec(foreground).into()));
+}
+
+#[nvim_oxi::test]
+#[cfg_attr(feature = "neovim-0-11", ignore = "deprecated in 0.11")]
+fn hl_cterm_colors() {
+    let opts = SetHighlightOpts::builder()
+        .foreground(Color::Rgb(255, 0, 0))
+        .ctermfg(Color::Terminal(196))
+        .ctermbg(Color::Named("Blue".into()))
+        .build();
+    api::set_hl(0, "OxiCterm", &opts).unwrap();
+
+    let infos = api::get_hl_by_name("OxiCterm", true).unwrap();
+    assert_eq!(infos.foreground, Some(Color::Rgb(255, 0, 0)));
+
+    let infos = api::get_hl_by_name("OxiCterm", false).unwrap();
+    assert_eq!(infos.foreground, Some(Color::Terminal(196)));
+    assert!(matches!(infos.background, Some(Color::Terminal(_))));
 }
 
 #[nvim_oxi::test]
 #[cfg_attr(feature = "neovim-0-11", ignore = "deprecated in 0.11")]
 fn hl_link() {
     let base_fg = "#579dd6";
-    let base_opts = SetHighlightOpts::builder().foreground(base_fg).build();
+    let base_opts = SetHighlightOpts::builder()
+        .foreground(base_fg.parse::<Color>().unwrap())
+        .build();
     nvim_oxi::api::set_hl(0, "Base", &base_opts).unwrap();
 
     let linked_opts = SetHighlightOpts::builder().link("Base").build();
     nvim_oxi::api::set_hl(0, "Linked", &linked_opts).unwrap();
 
     let infos = api::get_hl_by_name("Linked", true).unwrap();
-    assert_eq!(infos.foreground, Some(hex_to_dec(base_fg)));
+    assert_eq!(infos.foreground, Some(hex_to_dec(base_fg).into()));
+}
+
+#[nvim_oxi::test]
+fn link_hl() {
+    let base_fg = "#579dd6";
+    let base_opts = SetHighlightOpts::builder()
+        .foreground(base_fg.parse::<Color>().unwrap())
+        .build();
+    api::set_hl(0, "OxiBase", &base_opts).unwrap();
+
+    let opts = LinkHlOpts::builder().force(true).build();
+    api::link_hl("OxiLinked", "OxiBase", &opts).unwrap();
+
+    let opts = GetHighlightOpts::builder().name("OxiLinked").build();
+    let GetHlInfos::Single(infos) = api::get_hl(0, &opts).unwrap() else {
+        panic!("expected a single group")
+    };
+    assert_eq!(infos.link.as_deref(), Some("OxiBase"));
+
+    let opts =
+        GetHighlightOpts::builder().name("OxiLinked").link(false).build();
+    let GetHlInfos::Single(infos) = api::get_hl(0, &opts).unwrap() else {
+        panic!("expected a single group")
+    };
+    assert_eq!(infos.foreground, Some(hex_to_dec(base_fg).into()));
 }
 
 #[nvim_oxi::test]
@@ -197,6 +514,59 @@ fn hl_underline() {
     assert_eq!(Some(true), infos.underline);
 }
 
+#[nvim_oxi::test]
+fn inspect() {
+    let dict =
+        Dictionary::from_iter([("foo", Dictionary::from_iter([("bar", 42)]))]);
+    assert_eq!(
+        Ok("{\n  foo = {\n    bar = 42\n  }\n}".to_owned()),
+        api::inspect(dict)
+    );
+
+    assert_eq!(Ok("nil".to_owned()), api::inspect(()));
+}
+
+#[nvim_oxi::test]
+fn inspect_recursive() {
+    use nvim_oxi::lua::{Pushable, ffi};
+
+    /// A table containing a reference to itself.
+    struct Recursive;
+
+    impl Pushable for Recursive {
+        unsafe fn push(self, lstate: *mut ffi::State) -> std::ffi::c_int {
+            unsafe {
+                ffi::lua_createtable(lstate, 0, 1);
+                ffi::lua_pushstring(lstate, c"self".as_ptr());
+                ffi::lua_pushvalue(lstate, -2);
+                ffi::lua_rawset(lstate, -3);
+            }
+            1
+        }
+    }
+
+    let res = api::inspect(Recursive).unwrap();
+    assert!(res.contains("self = <table 1>"), "{res}");
+}
+
+#[nvim_oxi::test]
+fn input_getchar() {
+    use api::input::{self, KeyEvent, SpecialKey};
+
+    api::feedkeys(c"a", c"t", false);
+    assert_eq!(input::getchar(1), Ok(KeyEvent::Char('a')));
+
+    let left = api::replace_termcodes("<Left>", true, false, true);
+    api::feedkeys(&left, c"t", false);
+    assert_eq!(input::getchar(0), Ok(KeyEvent::Special(SpecialKey::Left)));
+
+    api::feedkeys(c"cd", c"t", false);
+    assert_eq!(input::getchar(2), Ok(KeyEvent::Char('d')));
+
+    api::feedkeys(c"b", c"t", false);
+    assert_eq!(input::get_char_nr(), Ok('b' as u32));
+}
+
 #[nvim_oxi::test]
 fn list_bufs() {
     let _ = api::create_buf(true, false);
@@ -265,6 +635,49 @@ fn notify_custom_err() {
     let _err = api::notify("", LogLevel::Error, &opts).unwrap_err();
 }
 
+#[nvim_oxi::test]
+fn require_lua_module() {
+    let table = nvim_oxi::lua::require::<nvim_oxi::Object>("table");
+    assert!(table.is_ok());
+
+    let res = nvim_oxi::lua::require::<nvim_oxi::Object>("nvim_oxi_missing");
+    assert_eq!(
+        res,
+        Err(api::Error::LuaModuleNotFound("nvim_oxi_missing".to_owned()))
+    );
+
+    // Errors that aren't strings are popped too.
+    api::exec_lua::<_, ()>(
+        "package.preload.nvim_oxi_table_err = function() error({}) end",
+        (),
+    )
+    .unwrap();
+    let res = nvim_oxi::lua::require::<nvim_oxi::Object>("nvim_oxi_table_err");
+    assert!(matches!(
+        res,
+        Err(api::Error::Lua(nvim_oxi::lua::Error::RuntimeError(_)))
+    ));
+}
+
+#[nvim_oxi::test]
+fn schedule_panic() {
+    api::set_vvar("errmsg", "").unwrap();
+
+    nvim_oxi::schedule(|()| -> Result<(), nvim_oxi::Error> { panic!("oops") });
+    nvim_oxi::schedule(|()| api::set_var("oxi_scheduled", true));
+
+    // Run the event loop until the second callback has been called, which
+    // means Neovim kept going after the first one panicked.
+    let called = api::exec_lua::<_, bool>(
+        "return vim.wait(1000, function() return vim.g.oxi_scheduled end)",
+        (),
+    );
+    assert_eq!(called, Ok(true));
+
+    let errmsg = api::error_vars().unwrap().errmsg;
+    assert_eq!(errmsg, "scheduled callback panicked: oops");
+}
+
 #[nvim_oxi::test]
 fn set_get_del_current_line() {
     let res = api::set_current_line("foo");
@@ -296,6 +709,33 @@ fn set_get_del_keymap() {
     assert_eq!(Ok(()), res);
 }
 
+#[nvim_oxi::test]
+fn set_get_keymap_callback() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let called = Rc::new(Cell::new(false));
+
+    let cloned = Rc::clone(&called);
+    let opts =
+        SetKeymapOpts::builder().callback(move |_| cloned.set(true)).build();
+
+    let res = api::set_keymap(Mode::Normal, "<F7>", "", &opts);
+    assert_eq!(Ok(()), res);
+
+    let keymap = api::get_keymap(Mode::Normal)
+        .find(|keymap| keymap.lhs == "<F7>")
+        .expect("keymap not found");
+
+    assert_eq!(None, keymap.rhs);
+    let callback = keymap.callback.expect("keymap has a callback");
+
+    assert_eq!(Ok(()), api::del_keymap(Mode::Normal, "<F7>"));
+
+    assert_eq!(Ok(()), callback.call(()));
+    assert!(called.get());
+}
+
 #[nvim_oxi::test]
 fn set_get_del_mark() {
     let mut buf = api::create_buf(true, false).unwrap();
@@ -340,6 +780,19 @@ fn set_get_option_value() {
     assert!(api::get_option_value::<bool>("modified", &opts).unwrap());
 }
 
+#[nvim_oxi::test]
+fn option_name() {
+    let opts = OptionOpts::default();
+    api::set_option_value(OptionName::Tabstop.as_str(), 4, &opts).unwrap();
+    let tabstop: u32 =
+        api::get_option_value(OptionName::Tabstop.as_str(), &opts).unwrap();
+    assert_eq!(tabstop, 4);
+
+    let infos =
+        api::get_option_info2(OptionName::Winfixbuf.as_str(), &opts).unwrap();
+    assert_eq!(infos.name, OptionName::Winfixbuf.to_string());
+}
+
 #[nvim_oxi::test]
 fn strwidth() {
     assert_eq!(Ok(2), api::strwidth("｜"));
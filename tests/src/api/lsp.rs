@@ -0,0 +1,16 @@
+use nvim_oxi::Object;
+use nvim_oxi::api::{Buffer, lsp};
+
+#[nvim_oxi::test]
+fn lsp_buf_request_no_clients() {
+    let buf = Buffer::current();
+
+    let requests = lsp::buf_request(
+        &buf,
+        "textDocument/hover",
+        Object::nil(),
+        |_err, _result| panic!("no server should reply"),
+    );
+
+    assert_eq!(requests.map(|requests| requests.is_empty()), Ok(true));
+}
@@ -0,0 +1,62 @@
+use nvim_oxi::api::marks::{self, Mark};
+use nvim_oxi::api::{self, Buffer};
+
+#[nvim_oxi::test]
+fn marks_set_get_del_local() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["foo", "bar"]).unwrap();
+
+    let mark = Mark { buffer: buf.clone(), row: 2, col: 1 };
+    marks::set('a', mark.clone()).unwrap();
+    assert_eq!(marks::get('a').unwrap(), Some(mark));
+
+    marks::del('a').unwrap();
+    assert_eq!(marks::get('a').unwrap(), None);
+}
+
+#[nvim_oxi::test]
+fn marks_set_get_del_global() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["foo"]).unwrap();
+
+    let mark = Mark { buffer: buf.clone(), row: 1, col: 0 };
+    marks::set('A', mark.clone()).unwrap();
+    assert_eq!(marks::get('A').unwrap(), Some(mark));
+
+    marks::del('A').unwrap();
+    assert_eq!(marks::get('A').unwrap(), None);
+}
+
+#[nvim_oxi::test]
+fn marks_get_buf_marks() {
+    let mut buf = Buffer::current();
+    buf.set_lines(.., true, ["foo", "bar"]).unwrap();
+
+    marks::set('b', Mark { buffer: buf.clone(), row: 1, col: 0 }).unwrap();
+    marks::set('B', Mark { buffer: buf.clone(), row: 2, col: 0 }).unwrap();
+
+    let names = |buf: &Buffer| {
+        marks::get_buf_marks(buf)
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
+    };
+
+    let names_in_buf = names(&buf);
+    assert!(names_in_buf.contains(&'b'));
+    assert!(names_in_buf.contains(&'B'));
+
+    let other = api::create_buf(true, false).unwrap();
+    let names_in_other = names(&other);
+    assert!(!names_in_other.contains(&'b'));
+    assert!(!names_in_other.contains(&'B'));
+}
+
+#[nvim_oxi::test]
+fn marks_invalid_names() {
+    let buf = Buffer::current();
+    assert!(marks::get('!').is_err());
+    assert!(marks::del('!').is_err());
+    assert!(marks::set('1', Mark { buffer: buf, row: 1, col: 0 }).is_err());
+}
@@ -1,9 +1,15 @@
 mod autocmd;
 mod buffer;
 mod command;
+mod diagnostic;
 mod extmark;
 mod global;
+mod lsp;
+mod marks;
+mod rpc;
+mod spell;
 mod tabpage;
+mod ui;
 mod vimscript;
 mod win_config;
 mod window;
@@ -0,0 +1,13 @@
+use nvim_oxi::Array;
+use nvim_oxi::api::{self, rpc};
+
+#[nvim_oxi::test]
+fn rpc_invalid_channel() {
+    let channel_id = u32::MAX;
+
+    let res = rpc::send(channel_id, "nvim_oxi_test", (1, "one"));
+    assert!(matches!(res, Err(api::Error::Nvim(_))), "{res:?}");
+
+    let res = rpc::request::<_, ()>(channel_id, "nvim_oxi_test", Array::new());
+    assert!(matches!(res, Err(api::Error::Nvim(_))), "{res:?}");
+}
@@ -0,0 +1,29 @@
+use nvim_oxi::api::spell::{self, SpellAddType, SpellBadType};
+use nvim_oxi::api::{Buffer, Window};
+
+#[nvim_oxi::test(cmd = "setlocal spell spelllang=en")]
+fn spell_bad_word_suggest_add_word() {
+    Buffer::current().set_lines(.., true, ["helo wrld"]).unwrap();
+    Window::current().set_cursor(1, 0).unwrap();
+
+    assert_eq!(
+        spell::bad_word(),
+        Ok(Some(("helo".to_owned(), SpellBadType::Bad)))
+    );
+
+    let suggestions = spell::suggest("helo", 5).unwrap();
+    assert!(suggestions.len() <= 5);
+    assert!(suggestions.iter().any(|word| word == "hello"));
+
+    spell::add_word("helo", SpellAddType::Good, false).unwrap();
+    assert_eq!(
+        spell::bad_word(),
+        Ok(Some(("wrld".to_owned(), SpellBadType::Bad)))
+    );
+
+    spell::add_word("helo", SpellAddType::Undo, false).unwrap();
+    assert_eq!(
+        spell::bad_word(),
+        Ok(Some(("helo".to_owned(), SpellBadType::Bad)))
+    );
+}
@@ -1,4 +1,4 @@
-use nvim_oxi::api::TabPage;
+use nvim_oxi::api::{self, TabPage};
 
 #[nvim_oxi::test]
 fn get_list_wins() {
@@ -21,11 +21,36 @@ fn tabpage_get_number() {
     assert_eq!(Ok(1), TabPage::current().get_number())
 }
 
+#[nvim_oxi::test]
+fn get_tabpage_by_number() {
+    api::command("tabnew").unwrap();
+    api::command("tabnew").unwrap();
+
+    let tabs = api::list_tabpages().collect::<Vec<_>>();
+    let numbers =
+        tabs.iter().map(|tab| tab.get_number().unwrap()).collect::<Vec<_>>();
+    assert_eq!(numbers, [1, 2, 3]);
+
+    assert_eq!(api::get_tabpage_by_number(2), Ok(Some(tabs[1].clone())));
+    assert_eq!(api::get_tabpage_by_number(0), Ok(None));
+    assert_eq!(api::get_tabpage_by_number(4), Ok(None));
+}
+
 #[nvim_oxi::test]
 fn is_valid() {
     assert!(TabPage::current().is_valid());
 }
 
+#[nvim_oxi::test]
+fn is_valid_after_tabclose() {
+    nvim_oxi::api::command("tabnew").unwrap();
+    let tab = TabPage::current();
+    assert!(tab.is_valid());
+
+    nvim_oxi::api::command("tabclose").unwrap();
+    assert!(!tab.is_valid());
+}
+
 #[nvim_oxi::test]
 fn tabpage_set_get_del_var() {
     let mut tab = TabPage::current();
@@ -0,0 +1,23 @@
+use nvim_oxi::api::{self, opts::*, ui::UiInfoSnapshot};
+
+#[nvim_oxi::test]
+fn ui_info_snapshot() {
+    let snapshot = UiInfoSnapshot::capture().unwrap();
+    assert!(snapshot.columns > 0 && snapshot.lines > 0);
+
+    let opts = OptionOpts::default();
+    api::set_option_value("showtabline", 2, &opts).unwrap();
+    api::set_option_value("laststatus", 0, &opts).unwrap();
+    api::set_option_value("cmdheight", 2, &opts).unwrap();
+
+    let snapshot = UiInfoSnapshot::capture().unwrap();
+    assert_eq!(snapshot.tabline_height, 1);
+    assert_eq!(snapshot.statusline_height, 0);
+    assert_eq!(snapshot.cmdheight, 2);
+
+    api::set_option_value("laststatus", 1, &opts).unwrap();
+    assert_eq!(UiInfoSnapshot::capture().unwrap().statusline_height, 0);
+
+    api::command("split").unwrap();
+    assert_eq!(UiInfoSnapshot::capture().unwrap().statusline_height, 1);
+}
@@ -1,5 +1,21 @@
+use all_asserts::*;
 use nvim_oxi::api::{self, opts::*, types::*};
 
+#[nvim_oxi::test]
+fn bufnr_bufname_bufexists() {
+    let mut buf = api::create_buf(true, false).unwrap();
+    buf.set_name("foo.txt").unwrap();
+
+    assert!(api::bufexists("foo.txt"));
+    assert!(!api::bufexists("bar.txt"));
+
+    assert_eq!(Ok(Some(buf.clone())), api::bufnr("foo.txt"));
+    assert_eq!(Ok(None), api::bufnr("bar.txt"));
+    assert_eq!(Ok(Some(api::Buffer::current())), api::bufnr("%"));
+
+    assert_eq!(Ok("foo.txt".to_owned()), api::bufname(&buf));
+}
+
 #[nvim_oxi::test]
 fn call_function() {
     let res = api::call_function::<_, usize>("strwidth", ("foo bar",));
@@ -51,6 +67,15 @@ fn exec() {
     assert_eq!(Ok(Some("2".into())), add);
 }
 
+#[nvim_oxi::test]
+fn exec2_output() {
+    assert_eq!(Ok("".into()), api::exec2_output(":"));
+    assert_eq!(Ok("2".into()), api::exec2_output("echo 1 + 1"));
+
+    let output = api::exec2_output("digraphs").unwrap();
+    assert_lt!(0, output.to_string_lossy().lines().count());
+}
+
 #[nvim_oxi::test]
 fn parse_cmd_basic() {
     let res = api::parse_cmd("echo 'foo'", &Default::default());
@@ -91,6 +116,42 @@ fn parse_cmd_basic() {
     assert_eq!(Some(CmdRange::None), infos.range);
 }
 
+#[nvim_oxi::test]
+fn get_messages() {
+    api::command("messages clear").unwrap();
+    assert_eq!(Ok(Vec::new()), api::get_messages(&Default::default()));
+
+    api::command("echomsg 'foo' | echomsg 'bar' | echomsg 'baz'").unwrap();
+
+    let messages = api::get_messages(&Default::default()).unwrap();
+    let contents =
+        messages.iter().map(|msg| msg.content.as_str()).collect::<Vec<_>>();
+    assert_eq!(contents, ["foo", "bar", "baz"]);
+
+    let opts = GetMessagesOpts::builder().count(2).build();
+    let messages = api::get_messages(&opts).unwrap();
+    let contents =
+        messages.iter().map(|msg| msg.content.as_str()).collect::<Vec<_>>();
+    assert_eq!(contents, ["bar", "baz"]);
+}
+
+#[nvim_oxi::test]
+fn getcwd() {
+    let global = api::getcwd(CwdScope::Global).unwrap();
+
+    let win =
+        api::split(SplitCommand::Below, None, &Default::default()).unwrap();
+    api::command("lcd /tmp").unwrap();
+
+    let tmp = std::path::Path::new("/tmp").canonicalize().unwrap();
+    assert_eq!(Ok(tmp), api::getcwd(CwdScope::Window(win)));
+    assert_eq!(Ok(global.clone()), api::getcwd(CwdScope::Global));
+    assert_eq!(
+        Ok(global),
+        api::getcwd(CwdScope::Tab(api::get_current_tabpage()))
+    );
+}
+
 #[nvim_oxi::test]
 fn parse_expression_basic() {
     let res = api::parse_expression("lua print('a')", "", true);
@@ -220,3 +281,81 @@ fn parse_expression_basic() {
 
     assert_eq!(14, len);
 }
+
+#[nvim_oxi::test]
+fn char2nr_nr2char() {
+    assert_eq!(api::char2nr('A'), Ok(65));
+    assert_eq!(api::char2nr('é'), Ok(0xe9));
+    assert_eq!(api::nr2char(65, true), Ok('A'));
+    assert_eq!(api::nr2char(0x1f600, true), Ok('😀'));
+    assert_eq!(
+        api::nr2char(0xd800, true),
+        Err(api::Error::InvalidCodepoint(0xd800))
+    );
+}
+
+#[nvim_oxi::test]
+fn get_syntax_id_at_syn_id_attr() {
+    let mut buf = api::Buffer::current();
+    buf.set_lines(.., true, ["let x = 1"]).unwrap();
+    api::command("syntax on").unwrap();
+    api::command("syntax keyword OxiKeyword let").unwrap();
+    api::command("highlight OxiKeyword gui=bold cterm=bold").unwrap();
+
+    let id = api::get_syntax_id_at(&buf, 1, 0).unwrap();
+    assert_ne!(id, 0);
+    assert_eq!(api::syn_id_attr(id, SynAttr::Name).unwrap(), "OxiKeyword");
+    assert_eq!(api::syn_id_attr(id, SynAttr::Bold).unwrap(), "1");
+    assert_eq!(api::syn_id_attr(id, SynAttr::Italic).unwrap(), "");
+
+    let none = api::get_syntax_id_at(&buf, 1, 4).unwrap();
+    assert_eq!(none, 0);
+}
+
+#[nvim_oxi::test]
+fn sign_getdefined() {
+    api::command(
+        "sign define OxiSign text=>> texthl=Error linehl=Search numhl=Title",
+    )
+    .unwrap();
+
+    let signs = api::sign_getdefined(Some("OxiSign")).unwrap();
+    assert_eq!(signs.len(), 1);
+
+    let sign = &signs[0];
+    assert_eq!(sign.name, "OxiSign");
+    assert_eq!(sign.text, ">>");
+    assert_eq!(sign.texthl, "Error");
+    assert_eq!(sign.linehl, "Search");
+    assert_eq!(sign.numhl, "Title");
+    assert_eq!(sign.culhl, "");
+
+    let all = api::sign_getdefined(None).unwrap();
+    assert!(all.iter().any(|sign| sign.name == "OxiSign"));
+
+    assert!(api::sign_getdefined(Some("OxiNoSuchSign")).unwrap().is_empty());
+}
+
+#[nvim_oxi::test]
+fn has() {
+    assert!(api::has(api::features::NVIM));
+    assert!(api::has(api::features::NVIM_0_10));
+    assert!(!api::has("nvim-oxi-no-such-feature"));
+}
+
+#[nvim_oxi::test]
+fn vimscript_printf() {
+    assert_eq!(
+        api::vimscript_printf("%s, %s!", ("Hello", "world")),
+        Ok("Hello, world!".to_owned())
+    );
+    assert_eq!(api::vimscript_printf("%05d", (42,)), Ok("00042".to_owned()));
+    assert_eq!(api::vimscript_printf("%x", 255), Ok("ff".to_owned()));
+    assert_eq!(api::vimscript_printf("100%%", ()), Ok("100%".to_owned()));
+
+    // Not enough arguments for the `%`-items.
+    assert!(api::vimscript_printf("%s %d", ("a",)).is_err());
+
+    // Invalid `%`-item.
+    assert!(api::vimscript_printf("%y", (1,)).is_err());
+}
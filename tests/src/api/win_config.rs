@@ -163,3 +163,36 @@ fn set_config() {
 
     assert_eq!(Ok(()), win.set_config(&config));
 }
+
+#[nvim_oxi::test]
+fn get_set_config_roundtrip() {
+    let buf = api::create_buf(true, true).unwrap();
+
+    let initial = WindowConfig::builder()
+        .relative(WindowRelativeTo::Editor)
+        .height(10)
+        .width(5)
+        .row(2)
+        .col(3)
+        .zindex(60u32)
+        .build();
+
+    let mut win = api::open_win(&buf, false, &initial).unwrap();
+
+    let mut config = win.get_config().unwrap();
+    config.width = Some(20);
+    win.set_config(&config).unwrap();
+
+    let got = win.get_config().unwrap();
+    assert_eq!(got.width, Some(20));
+    assert_eq!(got.height, initial.height);
+    assert_eq!(got.row, initial.row);
+    assert_eq!(got.col, initial.col);
+    assert_eq!(got.zindex, initial.zindex);
+}
+
+#[nvim_oxi::test]
+fn get_config_invalid_window() {
+    let win = Window::from(9999);
+    assert!(win.get_config().is_err());
+}
@@ -33,6 +33,39 @@ fn close_hide() {
     assert_eq!(Ok(()), win.hide());
 }
 
+#[nvim_oxi::test]
+fn close_last_window() {
+    let res = Window::current().close(true);
+    assert!(res.is_err(), "{res:?}");
+}
+
+#[nvim_oxi::test(cmd = "set nohidden")]
+fn close_modified() {
+    let mut win =
+        api::split(SplitCommand::Below, None, &Default::default()).unwrap();
+
+    let mut buf = api::create_buf(true, false).unwrap();
+    buf.set_lines(.., true, ["foo"]).unwrap();
+    win.set_buf(&buf).unwrap();
+
+    let res = win.clone().close(false);
+    assert!(res.is_err(), "{res:?}");
+    assert!(win.is_valid());
+
+    assert_eq!(Ok(()), win.clone().close(true));
+    assert!(!win.is_valid());
+}
+
+#[nvim_oxi::test]
+fn is_valid_after_quit() {
+    let win =
+        api::split(SplitCommand::Below, None, &Default::default()).unwrap();
+    assert!(win.is_valid());
+
+    api::command("quit").unwrap();
+    assert!(!win.is_valid());
+}
+
 #[nvim_oxi::test]
 fn win_get_number() {
     assert_eq!(Ok(1), Window::current().get_number());
@@ -84,6 +117,16 @@ fn get_set_height_width() {
     assert_eq!(10, win.get_width().unwrap());
 }
 
+#[nvim_oxi::test]
+fn set_get_hl_ns() {
+    let mut win = Window::current();
+    assert_eq!(Ok(None), win.get_hl_ns());
+
+    let ns_id = api::create_namespace("set_get_hl_ns");
+    assert_eq!(Ok(()), win.set_hl_ns(ns_id));
+    assert_eq!(Ok(Some(ns_id)), win.get_hl_ns());
+}
+
 #[nvim_oxi::test]
 fn get_tabpage() {
     assert_eq!(Ok(TabPage::current()), Window::current().get_tabpage())
@@ -124,4 +167,60 @@ fn win_set_get_del_var() {
     win.set_var("foo", 42).unwrap();
     assert_eq!(Ok(42), win.get_var("foo"));
     assert_eq!(Ok(()), win.del_var("foo"));
+    assert!(win.get_var::<i32>("foo").is_err());
+    assert!(win.del_var("foo").is_err());
+}
+
+#[nvim_oxi::test]
+fn split() {
+    use api::opts::SplitOpts;
+
+    let first = Window::current();
+
+    let below =
+        api::split(SplitCommand::Below, None, &Default::default()).unwrap();
+    assert_eq!(below, Window::current());
+
+    let buf = api::create_buf(true, false).unwrap();
+    let opts = SplitOpts::builder().size(20).build();
+    let right = api::split(SplitCommand::Right, Some(&buf), &opts).unwrap();
+    assert_eq!(buf, right.get_buf().unwrap());
+    assert_eq!(Ok(20), right.get_width());
+
+    assert_eq!(3, api::list_wins().len());
+
+    // `first` is above `below`, which is split vertically with `right`.
+    let (first_row, _) = first.get_position().unwrap();
+    let (below_row, below_col) = below.get_position().unwrap();
+    let (right_row, right_col) = right.get_position().unwrap();
+    assert!(first_row < below_row);
+    assert_eq!(below_row, right_row);
+    assert!(below_col < right_col);
+}
+
+#[nvim_oxi::test]
+fn get_cursor_position() {
+    let mut buf = api::Buffer::current();
+    buf.set_lines(.., true, ["first", "héllo"]).unwrap();
+
+    let mut win = Window::current();
+    win.set_cursor(2, 4).unwrap();
+
+    let pos = win.get_cursor_position().unwrap();
+    assert_eq!(pos.row_0(), 1);
+    assert_eq!(pos.row_1(), 2);
+    assert_eq!(pos.col_byte(), 4);
+    assert_eq!(pos.col_char(), 3);
+}
+
+#[nvim_oxi::test]
+fn show_message() {
+    let win = Window::current();
+
+    win.show_message("oxi warning", LogLevel::Warn).unwrap();
+    win.show_message("oxi hidden", LogLevel::Off).unwrap();
+
+    let messages = api::exec("messages", true).unwrap().unwrap();
+    assert!(messages.contains("oxi warning"));
+    assert!(!messages.contains("oxi hidden"));
 }
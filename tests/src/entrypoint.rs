@@ -0,0 +1,58 @@
+//! Tests about the entrypoint generated by the `#[nvim_oxi::plugin]` macro.
+
+use core::ffi::c_int;
+
+use nvim_oxi::lua::ffi::State;
+
+#[nvim_oxi::plugin]
+fn entrypoint_symbol() {}
+
+/// The signature Neovim expects from the function called by `require`, as
+/// documented in `:h lua-require`. This doesn't compile if the generated
+/// function has a different one.
+const _: unsafe extern "C" fn(*mut State) -> c_int = luaopen_entrypoint_symbol;
+
+/// Checks that the compiled library exports the generated function under the
+/// name Neovim looks for, i.e. `luaopen_` followed by the plugin's name.
+///
+/// The library can't be loaded with `dlopen()` outside of Neovim because it
+/// references symbols defined by the Neovim binary, so this reads its symbol
+/// table with `nm` instead.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+#[test]
+fn luaopen_symbol_is_exported() {
+    use std::process::Command;
+
+    let library_path =
+        nvim_oxi::tests::test_macro::library_path(env!("CARGO_MANIFEST_PATH"))
+            .unwrap();
+
+    // On macOS exported symbols are prefixed with an underscore, and only the
+    // global ones can be looked up. On Linux they're in the dynamic table.
+    let (symbols_flag, symbol) = if cfg!(target_os = "macos") {
+        ("-g", "_luaopen_entrypoint_symbol")
+    } else {
+        ("-D", "luaopen_entrypoint_symbol")
+    };
+
+    let output = Command::new("nm")
+        .args([symbols_flag, "--defined-only"])
+        .arg(library_path.as_str())
+        .output()
+        .expect("couldn't run nm");
+
+    assert!(output.status.success(), "{output:?}");
+
+    let symbols = String::from_utf8_lossy(&output.stdout);
+
+    let is_exported = symbols.lines().any(|line| {
+        let mut fields = line.split_whitespace().rev();
+        let (Some(name), Some(kind)) = (fields.next(), fields.next()) else {
+            return false;
+        };
+        // `T` is a global symbol in the text (code) section.
+        name == symbol && kind == "T"
+    });
+
+    assert!(is_exported, "{symbol} isn't exported by {library_path}");
+}